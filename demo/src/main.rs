@@ -47,13 +47,11 @@ fn main() -> Result<()> {
         );
     }
 
-    for entity in level.triggerables.entities() {
-        if let demo::Entity::TriggerArea(trigger) = &entity.entity {
-            for entity in &trigger.on_trigger {
-                let entity = entity.find(&world).unwrap();
-                if let demo::Entity::MessagePopUp(msg) = &entity.entity {
-                    println!("{}", msg.text);
-                }
+    for trigger in level.triggerables.trigger_areas() {
+        for entity in &trigger.entity.on_trigger {
+            let entity = entity.find(&world).unwrap();
+            if let demo::Entity::MessagePopUp(msg) = &entity.entity {
+                println!("{}", msg.text);
             }
         }
     }
@@ -68,73 +66,51 @@ fn main() -> Result<()> {
             zoom: 2.0,
         });
 
-        for (pos, tile) in level
-            .tiles
-            .rect(Vector2::zero(), level.tiles.size())
-            .filter_map(|(pos, tile)| tile.map(|tile| (pos, tile)))
-        {
-            d.draw_texture_rec(
-                tilesets.get(&demo::Tiles::TILESET_ID).unwrap(),
-                rrect(
-                    tile.position.x * level.collisions.grid_size().x,
-                    tile.position.y * level.collisions.grid_size().y,
-                    level.collisions.grid_size().x
-                        * if tile.flip.horizontal() { -1.0 } else { 1.0 },
-                    level.collisions.grid_size().y * if tile.flip.vertical() { -1.0 } else { 1.0 },
-                ),
-                pos * level.collisions.grid_size(),
-                Color::WHITE,
-            );
-        }
-
-        for (pos, tiles) in level
-            .collisions
-            .autotile_rect(Vector2::zero(), level.collisions.size)
-        {
-            for tile in tiles {
-                d.draw_texture_rec(
-                    tilesets.get(&demo::Collisions::TILESET_ID).unwrap(),
-                    rrect(
-                        tile.position.x * level.collisions.grid_size().x,
-                        tile.position.y * level.collisions.grid_size().y,
-                        level.collisions.grid_size().x
-                            * if tile.flip.horizontal() { -1.0 } else { 1.0 },
-                        level.collisions.grid_size().y
-                            * if tile.flip.vertical() { -1.0 } else { 1.0 },
-                    ),
-                    pos * level.collisions.grid_size(),
-                    Color::WHITE,
-                );
+        for layer in level.layers().filter(|layer| layer.visible()) {
+            let Some(tileset) = layer.tileset_id().and_then(|id| tilesets.get(&id)) else {
+                continue;
+            };
+            let grid_size = layer.grid_size();
+            for y in 0..layer.size().y as i32 {
+                for x in 0..layer.size().x as i32 {
+                    let pos = Vector2::new(x as f32, y as f32);
+                    for tile in layer.tiles(pos) {
+                        d.draw_texture_rec(
+                            tileset,
+                            rrect(
+                                tile.position.x * grid_size.x,
+                                tile.position.y * grid_size.y,
+                                grid_size.x * if tile.flip.horizontal() { -1.0 } else { 1.0 },
+                                grid_size.y * if tile.flip.vertical() { -1.0 } else { 1.0 },
+                            ),
+                            tile.pixel_position(pos, grid_size) + layer.total_offset(),
+                            Color::WHITE.fade(tile.alpha * layer.opacity()),
+                        );
+                    }
+                }
             }
         }
+
         for entity in level.triggerables.entities() {
             d.draw_rectangle_v(entity.top_left(), entity.size, Color::new(0, 255, 0, 30));
         }
         for entity in level.game_entities.entities() {
-            if let demo::Entity::Enemy(enemy) = &entity.entity {
-                let tile = enemy.enemy_type.icon().unwrap();
+            let tile = if let demo::Entity::Enemy(enemy) = &entity.entity {
+                enemy.enemy_type.icon()
+            } else if let demo::RenderMode::Tile(tile) = entity.entity.render_mode() {
+                Some(tile)
+            } else {
+                None
+            };
+            if let Some(tile) = tile {
                 d.draw_texture_pro(
-                    tilesets.get(&demo::MonsterType::TILESET_ID).unwrap(),
-                    rrect(tile.x * 16.0, tile.y * 16.0, 16.0, 16.0),
+                    tilesets.get(&tile.tileset).unwrap(),
                     rrect(
-                        entity.top_left().x,
-                        entity.top_left().y,
-                        entity.size.x,
-                        entity.size.y,
+                        tile.pixel_position.x,
+                        tile.pixel_position.y,
+                        tile.pixel_size.x,
+                        tile.pixel_size.y,
                     ),
-                    Vector2::zero(),
-                    0.0,
-                    Color::WHITE,
-                );
-            } else if let demo::RenderMode::Tile {
-                tileset,
-                tile,
-                size,
-            } = entity.entity.render_mode()
-            {
-                d.draw_texture_pro(
-                    tilesets.get(&tileset).unwrap(),
-                    rrect(tile.x * 16.0, tile.y * 16.0, size.x * 16.0, size.y * 16.0),
                     rrect(
                         entity.top_left().x,
                         entity.top_left().y,
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+pub use crate::naming::*;
 pub use crate::schema::*;
 pub use anyhow::*;
 pub use codegen::{Block, Scope};
@@ -13,15 +14,24 @@ pub struct Preferences {
     pub serde: bool,
     pub vector: Option<String>,
     pub color: Option<String>,
+    pub validate: bool,
+    pub spatial_index: bool,
+    pub identifier_style: IdentifierStyle,
 }
 
 impl Preferences {
+    /// Naming policy for every identifier that comes from the project
     pub fn to_case(&self, str: &str, case: Case) -> String {
+        use convert_case::{Boundary, Casing};
         if self.preserve_case {
-            str.to_owned()
-        } else {
-            use convert_case::Casing;
-            str.to_case(case)
+            return str.to_owned();
+        }
+        match self.identifier_style {
+            // Letter case doesn't separate words in these styles, only underscores do
+            IdentifierStyle::Lowercase | IdentifierStyle::Uppercase => str
+                .with_boundaries(&[Boundary::Underscore, Boundary::Hyphen, Boundary::Space])
+                .to_case(case),
+            IdentifierStyle::Capitalize | IdentifierStyle::Free => str.to_case(case),
         }
     }
 }
@@ -35,7 +45,9 @@ pub fn format_color(color: &str) -> Result<String> {
 
 #[derive(Default)]
 pub struct RsDefinitions {
+    pub names: RsNames,
     pub tilesets: HashMap<i64, RsTilesetDefinition>,
+    pub enums: HashMap<String, RsEnumDefinition>,
     pub layers: HashMap<String, RsLayerDefinition>,
     pub entities: HashMap<String, RsEntityDefinition>,
     pub level: RsLevelDefinition,
@@ -43,18 +55,95 @@ pub struct RsDefinitions {
     pub entity_instances: HashMap<String, RsEntityInstance>,
 }
 
+impl RsDefinitions {
+    pub fn format_tile_rect(&self, rect: &TilesetRectangle) -> Result<String> {
+        let tileset = self
+            .tilesets
+            .get(&rect.tileset_uid)
+            .context(format!("Tileset {} was not found!", rect.tileset_uid))?;
+        Ok(format!(
+            "TileRect::new({}, <UVec2 as VectorImpl>::new({} as _, {} as _), <UVec2 as VectorImpl>::new({} as _, {} as _), <UVec2 as VectorImpl>::new({} as _, {} as _), <UVec2 as VectorImpl>::new({} as _, {} as _))",
+            rect.tileset_uid,
+            rect.x as u32 / tileset.tile_size,
+            rect.y as u32 / tileset.tile_size,
+            rect.w as u32 / tileset.tile_size,
+            rect.h as u32 / tileset.tile_size,
+            rect.x,
+            rect.y,
+            rect.w,
+            rect.h,
+        ))
+    }
+
+    /// Formats a tile of a layer instance, and returns the cell it's on. Tiles outside
+    /// of the layer (like the ones of offset rules) go on the closest cell, keeping their offset
+    pub fn format_tile(
+        &self,
+        tileset_uid: i64,
+        grid_size: u32,
+        layer_json: &LayerInstance,
+        tile: &TileInstance,
+    ) -> Result<((i64, i64), String)> {
+        let tileset = self
+            .tilesets
+            .get(&tileset_uid)
+            .context(format!("Tileset {} was not found!", tileset_uid))?;
+        let grid_size = grid_size as i64;
+        let cell = (
+            tile.px[0]
+                .div_euclid(grid_size)
+                .min(layer_json.c_wid - 1)
+                .max(0),
+            tile.px[1]
+                .div_euclid(grid_size)
+                .min(layer_json.c_hei - 1)
+                .max(0),
+        );
+        Ok((
+            cell,
+            format!(
+                "Tile::new(<UVec2 as VectorImpl>::new({} as _, {} as _), {}, <IVec2 as VectorImpl>::new({} as _, {} as _), {:?})",
+                tile.src[0] as u32 / tileset.tile_size,
+                tile.src[1] as u32 / tileset.tile_size,
+                match tile.f {
+                    0 => "FlipMode::None",
+                    1 => "FlipMode::Horizontal",
+                    2 => "FlipMode::Vertical",
+                    3 => "FlipMode::Both",
+                    _ => bail!("Invalid flip mode: {}", tile.f),
+                },
+                tile.px[0] - cell.0 * grid_size,
+                tile.px[1] - cell.1 * grid_size,
+                tile.a as f32,
+            ),
+        ))
+    }
+}
+
 #[derive(Default)]
 pub struct RsLevelDefinition {
-    pub fields: HashMap<String, RsFieldType>,
+    pub fields: HashMap<String, RsField>,
 }
 
 pub struct RsTilesetDefinition {
     pub tile_size: u32,
+    /// Width in tiles
+    pub columns: u32,
+    /// Tiles without any transparent pixel, from the editor's cache
+    pub opaque_tiles: Vec<bool>,
+}
+
+#[derive(Default)]
+pub struct RsEnumDefinition {
+    /// LDtk identifier of the enum
+    pub identifier: String,
+    pub variants: HashMap<String, String>,
 }
 
 #[derive(Default)]
 pub struct RsEntityDefinition {
-    pub fields: HashMap<String, RsFieldType>,
+    pub name: String,
+    pub fields: HashMap<String, RsField>,
 }
 
 pub struct RsEntityInstance {
@@ -67,6 +156,7 @@ pub struct RsEntityInstance {
 pub enum RsLayerDefinition {
     IntGrid(RsIntGridDefinition),
     Tiles(RsTilesDefinition),
+    AutoLayer(RsAutoLayerDefinition),
     Entities,
 }
 
@@ -80,12 +170,92 @@ pub struct RsTilesDefinition {
     pub grid_size: u32,
 }
 
-pub struct RsAutoLayerDefinition {}
+pub struct RsAutoLayerDefinition {
+    pub grid_size: u32,
+    /// Group UID of each rule UID
+    pub rule_groups: HashMap<i64, i64>,
+}
+
+impl RsAutoLayerDefinition {
+    pub fn new(layer_json: &LayerDefinition) -> Self {
+        Self {
+            grid_size: layer_json.grid_size as u32,
+            rule_groups: layer_json
+                .auto_rule_groups
+                .iter()
+                .flat_map(|group| group.rules.iter().map(|rule| (rule.uid, group.uid)))
+                .collect(),
+        }
+    }
+}
 
 // * ------------------------------------ Fields ------------------------------------ * //
+pub struct RsField {
+    /// Rust name of the struct member
+    pub name: String,
+    pub field_type: RsFieldType,
+    pub constraints: RsFieldConstraints,
+}
+
+impl RsField {
+    pub fn parse(
+        preferences: &Preferences,
+        definitions: &RsDefinitions,
+        project: &LdtkJson,
+        field: &FieldDefinition,
+        members: &mut Namespace,
+    ) -> Result<Self> {
+        Ok(Self {
+            name: members.insert(preferences, &field.identifier, Case::Snake, "field"),
+            field_type: RsFieldType::parse(definitions, project, field)?,
+            constraints: RsFieldConstraints::parse(field)?,
+        })
+    }
+
+    /// Push runtime checks for this field into a `validate()` function.
+    /// `identifier` is the LDtk identifier used in errors
+    pub fn generate_validation(&self, identifier: &str, function: &mut codegen::Function) {
+        if self.constraints.constrains(&self.field_type) {
+            let mut block = Block::new("");
+            block.line(format!("let value = &self.{};", self.name));
+            self.constraints
+                .generate_validation(&self.field_type, identifier, &mut block);
+            function.push_block(block);
+        }
+    }
+
+    /// Check a value from the project and report broken constraints
+    pub fn check(&self, location: &str, value: Option<&serde_json::Value>) -> Result<()> {
+        for error in self.constraints.check(&self.field_type, value)? {
+            eprintln!("Warning: {location} {error}");
+        }
+        Ok(())
+    }
+}
+
+/// Values of every instance of a field definition, on levels and entities
+fn field_values(project: &LdtkJson, uid: i64) -> impl Iterator<Item = Option<&serde_json::Value>> {
+    project.levels.iter().flat_map(move |level| {
+        let entities = level
+            .layer_instances
+            .iter()
+            .flatten()
+            .flat_map(|layer| &layer.entity_instances)
+            .flat_map(|entity| &entity.field_instances);
+        level
+            .field_instances
+            .iter()
+            .chain(entities)
+            .filter(move |field| field.def_uid == uid)
+            .map(|field| field.value.as_ref().filter(|value| !value.is_null()))
+    })
+}
+
 pub enum RsFieldType {
     Option(Box<RsFieldType>),
     Array(Box<RsFieldType>),
+    FixedArray(Box<RsFieldType>, usize),
+    NonEmptyArray(Box<RsFieldType>),
     Enum(String),
     Int,
     Float,
@@ -99,10 +269,20 @@ pub enum RsFieldType {
 }
 
 impl RsFieldType {
-    pub fn parse(field: &FieldDefinition) -> Result<Self> {
-        fn parse_field_definition(field_type: &str, can_be_null: bool) -> Result<RsFieldType> {
+    /// Arrays are only fixed or non-empty if every instance of the field in the project is
+    pub fn parse(
+        definitions: &RsDefinitions,
+        project: &LdtkJson,
+        field: &FieldDefinition,
+    ) -> Result<Self> {
+        let parse_field_definition = |field_type: &str, can_be_null: bool| -> Result<RsFieldType> {
             let rs_type = if let Some(enumeration) = field_type.strip_prefix("LocalEnum.") {
-                RsFieldType::Enum(enumeration.to_owned())
+                let (name, _) = definitions
+                    .enums
+                    .iter()
+                    .find(|(_, definition)| definition.identifier == enumeration)
+                    .context(format!("Enum {} was not found!", enumeration))?;
+                RsFieldType::Enum(name.clone())
             } else if let Some(_enumeration) = field_type.strip_prefix("ExternEnum.") {
                 // TODO: External enums
                 bail!("External enums are not supported yet.");
@@ -125,7 +305,7 @@ impl RsFieldType {
             } else {
                 rs_type
             })
-        }
+        };
 
         Ok(
             if let Some(generic) = field
@@ -133,8 +313,39 @@ impl RsFieldType {
                 .strip_prefix("Array<")
                 .and_then(|postfix| postfix.strip_suffix('>'))
             {
-                let generic = parse_field_definition(generic, field.can_be_null)?;
-                RsFieldType::Array(Box::new(generic))
+                let generic = Box::new(parse_field_definition(generic, field.can_be_null)?);
+                let lengths = field_values(project, field.uid)
+                    .map(|value| value.and_then(|value| value.as_array()).map_or(0, Vec::len))
+                    .collect::<Vec<_>>();
+                let fits = |expected: &str, fits: &dyn Fn(usize) -> bool| {
+                    let broken = lengths.iter().filter(|&&length| !fits(length)).count();
+                    if broken > 0 {
+                        eprintln!(
+                            "Warning: Field {} has {broken} instances without {expected} elements, generated as a Vec",
+                            field.identifier
+                        );
+                    }
+                    broken == 0
+                };
+                match (field.array_min_length, field.array_max_length) {
+                    (Some(min), Some(max))
+                        if min == max
+                            && min > 0
+                            && fits(&format!("exactly {min}"), &|length| {
+                                length == min as usize
+                            }) =>
+                    {
+                        RsFieldType::FixedArray(generic, min as _)
+                    }
+                    (Some(min), max)
+                        if min > 0
+                            && max != Some(min)
+                            && fits("at least 1", &|length| length > 0) =>
+                    {
+                        RsFieldType::NonEmptyArray(generic)
+                    }
+                    _ => RsFieldType::Array(generic),
+                }
             } else {
                 parse_field_definition(&field.field_definition_type, field.can_be_null)?
             },
@@ -145,6 +356,10 @@ impl RsFieldType {
         match self {
             RsFieldType::Option(generic) => format!("Option<{}>", generic.string_type()),
             RsFieldType::Array(generic) => format!("Vec<{}>", generic.string_type()),
+            RsFieldType::FixedArray(generic, length) => {
+                format!("[{}; {}]", generic.string_type(), length)
+            }
+            RsFieldType::NonEmptyArray(generic) => format!("NonEmpty<{}>", generic.string_type()),
             RsFieldType::Enum(name) => name.clone(),
             RsFieldType::Int => "i32".to_owned(),
             RsFieldType::Float => "f32".to_owned(),
@@ -152,7 +367,7 @@ impl RsFieldType {
             RsFieldType::Bool => "bool".to_owned(),
             RsFieldType::Color => "Color".to_owned(),
             RsFieldType::Point => "UVec2".to_owned(),
-            RsFieldType::Tile => "(TilesetID, UVec2)".to_owned(),
+            RsFieldType::Tile => "TileRect".to_owned(),
             RsFieldType::FilePath => "std::path::PathBuf".to_owned(),
             RsFieldType::EntityRef => "EntityRef".to_owned(),
         }
@@ -200,15 +415,41 @@ impl RsFieldType {
 
         Ok(match self {
             RsFieldType::Option(_) => bail!("Unreachable: Option is already filtered out!"),
-            RsFieldType::Array(generic) => {
+            RsFieldType::Array(generic)
+            | RsFieldType::FixedArray(generic, _)
+            | RsFieldType::NonEmptyArray(generic) => {
                 let array = primitive!(as_array, "array");
                 let mut elements = Vec::with_capacity(array.len());
                 for element in array {
                     elements.push(generic.fmt_value(definitions, Some(element))?);
                 }
-                format!("vec![{}]", elements.join(", "))
+                match self {
+                    RsFieldType::FixedArray(_, length) => {
+                        ensure!(
+                            elements.len() == *length,
+                            "Array should contain exactly {} elements, found {}!",
+                            length,
+                            elements.len()
+                        );
+                        format!("[{}]", elements.join(", "))
+                    }
+                    RsFieldType::NonEmptyArray(_) => {
+                        ensure!(!elements.is_empty(), "Array should not be empty!");
+                        let first = elements.remove(0);
+                        format!("NonEmpty::new({}, vec![{}])", first, elements.join(", "))
+                    }
+                    _ => format!("vec![{}]", elements.join(", ")),
+                }
+            }
+            RsFieldType::Enum(name) => {
+                let variant = primitive!(as_str, "enum variant");
+                let variant = definitions
+                    .enums
+                    .get(name)
+                    .and_then(|enumeration| enumeration.variants.get(variant))
+                    .context(format!("Enum variant {}.{} was not found!", name, variant))?;
+                format!("{}::{}", name, variant)
             }
-            RsFieldType::Enum(name) => format!("{}::{}", name, primitive!(as_str, "enum variant")),
             RsFieldType::Int => primitive!(as_i64, "integer").to_string(),
             RsFieldType::Float => {
                 let value = primitive!(as_f64, "float");
@@ -226,18 +467,11 @@ impl RsFieldType {
                 )
             }
             RsFieldType::Tile => {
-                let tile = primitive!(as_object, "TilesetRect");
-                let tileset_id = object_i64!(tile.tilesetUid);
-                let tileset = definitions
-                    .tilesets
-                    .get(&tileset_id)
-                    .context("Tile field tileset was not found!")?;
-                format!(
-                    "({}, <UVec2 as VectorImpl>::new({} as _, {} as _))",
-                    tileset_id,
-                    object_i64!(tile.x) as u32 / tileset.tile_size,
-                    object_i64!(tile.y) as u32 / tileset.tile_size,
-                )
+                let tile = serde_json::from_value::<TilesetRectangle>(value.clone())
+                    .context(format!("Expected TilesetRect, found {}!", value))?;
+                definitions
+                    .format_tile_rect(&tile)
+                    .context("Tile field tileset was not found!")?
             }
             RsFieldType::FilePath => format!("\"{}\".into()", primitive!(as_str, "filepath")),
             RsFieldType::EntityRef => {
@@ -264,6 +498,251 @@ impl RsFieldType {
     }
 }
 
+pub struct RsFieldConstraints {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub regex: Option<regex::Regex>,
+    pub accept_file_types: Vec<String>,
+    pub array_min_length: Option<i64>,
+    pub array_max_length: Option<i64>,
+}
+
+impl RsFieldConstraints {
+    pub fn parse(field: &FieldDefinition) -> Result<Self> {
+        let regex = field.regex.as_deref().map(|regex| {
+            // LDtk stores regexes in JS notation: /pattern/flags
+            let pattern = match regex
+                .strip_prefix('/')
+                .and_then(|regex| regex.rsplit_once('/'))
+            {
+                Some((pattern, flags)) if flags.contains('i') => format!("(?i){pattern}"),
+                Some((pattern, _)) => pattern.to_owned(),
+                None => regex.to_owned(),
+            };
+            regex::Regex::new(&pattern).context(format!(
+                "Field {} has an invalid regex: {}!",
+                field.identifier, regex
+            ))
+        });
+        Ok(Self {
+            min: field.min,
+            max: field.max,
+            regex: regex.transpose()?,
+            accept_file_types: field
+                .accept_file_types
+                .iter()
+                .flatten()
+                .map(|extension| extension.trim_start_matches('.').to_lowercase())
+                .collect(),
+            array_min_length: field.array_min_length.filter(|_| field.is_array),
+            array_max_length: field.array_max_length.filter(|_| field.is_array),
+        })
+    }
+
+    /// Check a value from the project, returns a message for every broken constraint
+    pub fn check(
+        &self,
+        field_type: &RsFieldType,
+        value: Option<&serde_json::Value>,
+    ) -> Result<Vec<String>> {
+        let mut errors = Vec::new();
+        let Some(value) = value.filter(|value| !value.is_null()) else {
+            return Ok(errors);
+        };
+        match field_type {
+            RsFieldType::Option(generic) => errors.extend(self.check(generic, Some(value))?),
+            RsFieldType::Array(generic)
+            | RsFieldType::FixedArray(generic, _)
+            | RsFieldType::NonEmptyArray(generic) => {
+                let array = value
+                    .as_array()
+                    .context(format!("Expected array, found {}!", value))?;
+                if let Some(min) = self
+                    .array_min_length
+                    .filter(|&min| (array.len() as i64) < min)
+                {
+                    errors.push(format!(
+                        "should contain at least {} elements, found {}",
+                        min,
+                        array.len()
+                    ));
+                }
+                if let Some(max) = self
+                    .array_max_length
+                    .filter(|&max| (array.len() as i64) > max)
+                {
+                    errors.push(format!(
+                        "should contain at most {} elements, found {}",
+                        max,
+                        array.len()
+                    ));
+                }
+                for (index, element) in array.iter().enumerate() {
+                    for error in self.check(generic, Some(element))? {
+                        errors.push(format!("[{index}] {error}"));
+                    }
+                }
+            }
+            RsFieldType::Int | RsFieldType::Float => {
+                let number = value
+                    .as_f64()
+                    .context(format!("Expected number, found {}!", value))?;
+                if let Some(min) = self.min.filter(|&min| number < min) {
+                    errors.push(format!("should be at least {}, found {}", min, number));
+                }
+                if let Some(max) = self.max.filter(|&max| number > max) {
+                    errors.push(format!("should be at most {}, found {}", max, number));
+                }
+            }
+            RsFieldType::String => {
+                let string = value
+                    .as_str()
+                    .context(format!("Expected string, found {}!", value))?;
+                if let Some(regex) = &self.regex {
+                    if !regex.is_match(string) {
+                        errors.push(format!("should match /{}/, found {:?}", regex, string));
+                    }
+                }
+            }
+            RsFieldType::FilePath => {
+                let path = value
+                    .as_str()
+                    .context(format!("Expected filepath, found {}!", value))?;
+                if !self.accept_file_types.is_empty() {
+                    let extension = std::path::Path::new(path)
+                        .extension()
+                        .and_then(|extension| extension.to_str())
+                        .map(str::to_lowercase);
+                    if !extension
+                        .is_some_and(|extension| self.accept_file_types.contains(&extension))
+                    {
+                        errors.push(format!(
+                            "should be one of the file types {}, found {:?}",
+                            self.accept_file_types.join(", "),
+                            path
+                        ));
+                    }
+                }
+            }
+            _ => (),
+        }
+        Ok(errors)
+    }
+
+    /// Whether any of these constraints apply to a field of this type
+    pub fn constrains(&self, field_type: &RsFieldType) -> bool {
+        match field_type {
+            RsFieldType::Option(generic) => self.constrains(generic),
+            RsFieldType::Array(generic) => {
+                self.array_min_length.is_some()
+                    || self.array_max_length.is_some()
+                    || self.constrains(generic)
+            }
+            // Length is already enforced by the type
+            RsFieldType::FixedArray(generic, _) => self.constrains(generic),
+            RsFieldType::NonEmptyArray(generic) => {
+                self.array_min_length.is_some_and(|min| min > 1)
+                    || self.array_max_length.is_some()
+                    || self.constrains(generic)
+            }
+            RsFieldType::Int | RsFieldType::Float => self.min.is_some() || self.max.is_some(),
+            RsFieldType::String => self.regex.is_some(),
+            RsFieldType::FilePath => !self.accept_file_types.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Generate runtime checks for a field, expects a reference to it in `value`.
+    /// `name` is the LDtk identifier used in errors
+    pub fn generate_validation(&self, field_type: &RsFieldType, name: &str, block: &mut Block) {
+        if !self.constrains(field_type) {
+            return;
+        }
+        macro_rules! fail {
+            ($block:ident $condition:expr, $($message:tt)*) => {{
+                let mut check = Block::new(&format!("if {}", $condition));
+                check.line(format!(
+                    "return Err(ValidationError::new({:?}, {:?}));",
+                    name,
+                    format!($($message)*)
+                ));
+                $block.push_block(check);
+            }};
+        }
+
+        match field_type {
+            RsFieldType::Option(generic) => {
+                let mut some = Block::new("if let Some(value) = value");
+                self.generate_validation(generic, name, &mut some);
+                block.push_block(some);
+            }
+            RsFieldType::Array(generic)
+            | RsFieldType::FixedArray(generic, _)
+            | RsFieldType::NonEmptyArray(generic) => {
+                let (guaranteed_min, guaranteed_max) = match field_type {
+                    RsFieldType::FixedArray(_, length) => (*length as i64, Some(*length as i64)),
+                    RsFieldType::NonEmptyArray(_) => (1, None),
+                    _ => (0, None),
+                };
+                if let Some(min) = self.array_min_length.filter(|&min| min > guaranteed_min) {
+                    fail!(block format!("value.len() < {min}"), "should contain at least {min} elements");
+                }
+                if let Some(max) = self
+                    .array_max_length
+                    .filter(|&max| Some(max) != guaranteed_max)
+                {
+                    fail!(block format!("value.len() > {max}"), "should contain at most {max} elements");
+                }
+                if self.constrains(generic) {
+                    let mut elements = Block::new("for value in value.iter()");
+                    self.generate_validation(generic, name, &mut elements);
+                    block.push_block(elements);
+                }
+            }
+            RsFieldType::Int => {
+                // Fractional bounds allow the integers inside of them
+                if let Some(min) = self.min.map(|min| min.ceil() as i64) {
+                    fail!(block format!("*value < {}", min), "should be at least {}", min);
+                }
+                if let Some(max) = self.max.map(|max| max.floor() as i64) {
+                    fail!(block format!("*value > {}", max), "should be at most {}", max);
+                }
+            }
+            RsFieldType::Float => {
+                if let Some(min) = self.min {
+                    fail!(block format!("*value < {:?}", min as f32), "should be at least {}", min);
+                }
+                if let Some(max) = self.max {
+                    fail!(block format!("*value > {:?}", max as f32), "should be at most {}", max);
+                }
+            }
+            RsFieldType::String => {
+                if let Some(regex) = &self.regex {
+                    // Compiled once per field, the pattern was checked when generating
+                    block.line("static REGEX: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();");
+                    block.line(format!(
+                        "let regex = REGEX.get_or_init(|| regex::Regex::new({:?}).unwrap());",
+                        regex.as_str()
+                    ));
+                    fail!(block "!regex.is_match(value)", "should match /{}/", regex);
+                }
+            }
+            RsFieldType::FilePath => {
+                let pattern = self
+                    .accept_file_types
+                    .iter()
+                    .map(|extension| format!("{extension:?}"))
+                    .join(" | ");
+                fail!(
+                    block format!("!matches!(value.extension().and_then(|extension| extension.to_str()).map(str::to_lowercase).as_deref(), Some({pattern}))"),
+                    "should be one of the file types {}", self.accept_file_types.join(", ")
+                );
+            }
+            _ => (),
+        }
+    }
+}
+
 // * ------------------------------------ Macros ------------------------------------ * //
 #[macro_export]
 macro_rules! derive_rust_object {
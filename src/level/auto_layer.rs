@@ -0,0 +1,302 @@
+use crate::definitions::*;
+
+pub fn layer_definition(
+    preferences: &Preferences,
+    definitions: &mut RsDefinitions,
+    project: &LdtkJson,
+    layer_json: &LayerDefinition,
+    code: &mut Scope,
+    level: &mut codegen::Struct,
+) -> Result<()> {
+    // LDtk lets auto layers exist before a tileset is picked, they can't have tiles yet
+    if layer_json.tileset_def_uid.is_none() {
+        eprintln!(
+            "Warning: Auto layer {} has no tileset, it was skipped",
+            layer_json.identifier
+        );
+        return Ok(());
+    }
+    let layer_type_name = &definitions.names.layers[&layer_json.identifier]
+        .type_name
+        .clone();
+
+    let layer_struct = code.new_struct(layer_type_name).vis("pub");
+    derive_rust_object!(layer_struct preferences.serde,);
+    super::layer_fields(layer_struct);
+    auto_layer_fields(layer_struct);
+
+    super::impl_layer_trait(code, layer_type_name, layer_json);
+    super::impl_auto_layer(code, layer_type_name, layer_json)?;
+    impl_rule_groups(preferences, layer_type_name, layer_json, code);
+
+    // Layers without a source IntGrid have no cells for their rules to read
+    let source = layer_json
+        .auto_source_layer_def_uid
+        .and_then(|uid| project.defs.layers.iter().find(|layer| layer.uid == uid))
+        .filter(|source| matches!(source.purple_type, Type::IntGrid));
+    if let Some(source) = source {
+        impl_auto_rules(definitions, layer_json, source, code)?;
+    }
+
+    // * Update definitions
+    definitions.layers.insert(
+        layer_json.identifier.clone(),
+        RsLayerDefinition::AutoLayer(RsAutoLayerDefinition::new(layer_json)),
+    );
+
+    level
+        .new_field(
+            &definitions.names.layers[&layer_json.identifier].member,
+            layer_type_name,
+        )
+        .vis("pub");
+
+    Ok(())
+}
+
+/// Members of pure auto layers and autotiled IntGrid layers
+pub fn auto_layer_fields(layer_struct: &mut codegen::Struct) {
+    layer_struct.new_field("auto_tiles", "Vec<Vec<AutoTile>>");
+    layer_struct
+        .new_field("override_tileset", "Option<TilesetID>")
+        .vis("pub")
+        .doc("Tileset used instead of the one of the definition");
+    layer_struct
+        .new_field("seed", "u32")
+        .vis("pub")
+        .doc("Random seed of the auto layer rules");
+    layer_struct
+        .new_field("optional_rules", "Vec<u32>")
+        .vis("pub")
+        .doc("UIDs of the optional rule groups enabled on this instance");
+}
+
+pub fn layer_instance(
+    definition: &RsAutoLayerDefinition,
+    definitions: &RsDefinitions,
+    layer_rs: &mut Block,
+    layer_json: &LayerInstance,
+) -> Result<()> {
+    super::layer_instance_fields(layer_rs, layer_json);
+    auto_tiles(definition, definitions, layer_rs, layer_json)
+}
+
+/// Auto tiles and rule settings of pure auto layers and autotiled IntGrid layers
+pub fn auto_tiles(
+    definition: &RsAutoLayerDefinition,
+    definitions: &RsDefinitions,
+    layer_rs: &mut Block,
+    layer_json: &LayerInstance,
+) -> Result<()> {
+    let tileset_id = layer_json
+        .tileset_def_uid
+        .context("Tileset UID is not present in auto layer!")?;
+    let mut tiles = vec![Vec::new(); layer_json.c_wid as usize * layer_json.c_hei as usize];
+    for tile in &layer_json.auto_layer_tiles {
+        let rule = tile.d[0];
+        let ((x, y), tile) =
+            definitions.format_tile(tileset_id, definition.grid_size, layer_json, tile)?;
+        let Some(cell) = tiles.get_mut((x + y * layer_json.c_wid) as usize) else {
+            continue;
+        };
+        cell.push(format!(
+            "AutoTile::new({tile}, {rule}, {})",
+            definition
+                .rule_groups
+                .get(&rule)
+                .context(format!("Auto tile rule {rule} was not found!"))?
+        ));
+    }
+    let mut auto_tiles = Block::new("auto_tiles: vec!");
+    for tiles in tiles {
+        auto_tiles.line(format!("vec![{}],", tiles.join(", ")));
+    }
+    auto_tiles.after(",");
+    layer_rs.push_block(auto_tiles);
+    layer_rs.line(format!(
+        "override_tileset: {:?},",
+        layer_json.override_tileset_uid
+    ));
+    layer_rs.line(format!("seed: {},", layer_json.seed));
+    layer_rs.line(format!(
+        "optional_rules: vec![{}],",
+        layer_json.optional_rules.iter().join(", ")
+    ));
+    Ok(())
+}
+
+/// Constants naming the rule groups of a layer, to find out which group placed an auto tile
+pub fn impl_rule_groups(
+    preferences: &Preferences,
+    layer_type_name: &str,
+    layer_json: &LayerDefinition,
+    code: &mut Scope,
+) {
+    let layer_impl = code.new_impl(layer_type_name);
+    let mut constants = Namespace::new(&[
+        "GRID_SIZE",
+        "OPACITY",
+        "PARALLAX_SCALING",
+        "TILESET_ID",
+        "AUTO_RULES",
+    ]);
+    for group in &layer_json.auto_rule_groups {
+        let constant = constants.insert(preferences, &group.name, Case::UpperSnake, "rule group");
+        layer_impl.associate_const(constant, "u32", group.uid.to_string(), "pub");
+    }
+}
+
+/// Rule data of auto layers, to recompute their auto tiles at runtime from the cells of the
+/// source layer. Autotiled IntGrid layers are their own source
+pub fn impl_auto_rules(
+    definitions: &RsDefinitions,
+    layer_json: &LayerDefinition,
+    source_json: &LayerDefinition,
+    code: &mut Scope,
+) -> Result<()> {
+    let layer_type_name = &definitions.names.layers[&layer_json.identifier].type_name;
+    let tileset = definitions
+        .tilesets
+        .get(
+            &layer_json
+                .tileset_def_uid
+                .context("No tileset UID in auto layer!")?,
+        )
+        .context("Tileset from auto layer was not found!")?;
+    let grid_size = layer_json.grid_size as f64;
+
+    let mut groups = vec!["&[".to_owned()];
+    for group in &layer_json.auto_rule_groups {
+        groups.push(format!(
+            "    AutoRuleGroup {{ uid: {}, active: {}, optional: {}, rules: &[",
+            group.uid, group.active, group.is_optional
+        ));
+        for rule in &group.rules {
+            let mut tiles = Vec::new();
+            for tile_ids in &rule.tile_rects_ids {
+                let positions = tile_ids
+                    .iter()
+                    .map(|&tile_id| {
+                        (
+                            tile_id as u32 % tileset.columns,
+                            tile_id as u32 / tileset.columns,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let (left, right) = positions
+                    .iter()
+                    .map(|tile| tile.0)
+                    .minmax()
+                    .into_option()
+                    .unwrap_or_default();
+                let (top, bottom) = positions
+                    .iter()
+                    .map(|tile| tile.1)
+                    .minmax()
+                    .into_option()
+                    .unwrap_or_default();
+                let stamp = matches!(rule.tile_mode, TileMode::Stamp);
+                tiles.push(format!(
+                    "&[{}]",
+                    tile_ids
+                        .iter()
+                        .zip(positions)
+                        .map(|(&tile_id, (x, y))| {
+                            // Stamps are placed around the cell according to the pivot
+                            let (offset_x, offset_y) = if stamp {
+                                (
+                                    ((x - left) as f64 - rule.pivot_x * (right - left) as f64)
+                                        * grid_size,
+                                    ((y - top) as f64 - rule.pivot_y * (bottom - top) as f64)
+                                        * grid_size,
+                                )
+                            } else {
+                                (0.0, 0.0)
+                            };
+                            format!(
+                                "AutoRuleTile {{ tile: ({x}, {y}), offset: ({}, {}), opaque: {} }}",
+                                offset_x as i64 + rule.tile_x_offset,
+                                offset_y as i64 + rule.tile_y_offset,
+                                tileset
+                                    .opaque_tiles
+                                    .get(tile_id as usize)
+                                    .copied()
+                                    .unwrap_or_default(),
+                            )
+                        })
+                        .join(", ")
+                ));
+            }
+            groups.push(format!(
+                "        AutoRule {{ uid: {}, active: {}, size: {}, pattern: &{:?}, out_of_bounds_value: {:?}, chance: {:?}, alpha: {:?}, break_on_match: {}, flip_x: {}, flip_y: {}, checker: RuleChecker::{:?}, x_modulo: {}, y_modulo: {}, x_offset: {}, y_offset: {}, tiles: &[{}] }},",
+                rule.uid,
+                rule.active,
+                rule.size,
+                rule.pattern,
+                rule.out_of_bounds_value,
+                rule.chance,
+                rule.alpha as f32,
+                rule.break_on_match,
+                rule.flip_x,
+                rule.flip_y,
+                rule.checker,
+                rule.x_modulo,
+                rule.y_modulo,
+                rule.x_offset,
+                rule.y_offset,
+                tiles.join(", ")
+            ));
+        }
+        groups.push("    ] },".to_owned());
+    }
+    groups.push("]".to_owned());
+
+    let auto_rules = code.new_impl(layer_type_name);
+    let (int_grid_value, source) = if source_json.uid == layer_json.uid {
+        auto_rules.impl_trait("traits::AutoRules");
+        (auto_rules.new_fn("int_grid_value").arg_ref_self(), "self")
+    } else {
+        auto_rules.impl_trait("traits::SourcedAutoLayer");
+        auto_rules.associate_type(
+            "Source",
+            &definitions.names.layers[&source_json.identifier].type_name,
+        );
+        let int_grid_value = auto_rules
+            .new_fn("int_grid_value")
+            .arg("source", "&Self::Source");
+        (int_grid_value, "source")
+    };
+    int_grid_value
+        .arg("position", "IVec2")
+        .ret("Option<i32>")
+        .line("use traits::IndexableLayer;")
+        .line(format!(
+            "{source}.get(position).map(|tile| tile.value() as i32)"
+        ));
+    auto_rules.associate_const(
+        "AUTO_RULES",
+        "&'static [AutoRuleGroup]",
+        groups.join("\n"),
+        "",
+    );
+
+    let mut groups = Block::new("match value");
+    for value in &source_json.int_grid_values {
+        groups.line(format!("{} => Some({}),", value.value, value.group_uid));
+    }
+    groups.line("_ => None,");
+    auto_rules
+        .new_fn("int_grid_group")
+        .arg("value", "i32")
+        .ret("Option<u32>")
+        .push_block(groups);
+
+    auto_rules
+        .new_fn("set_autotiles")
+        .arg_mut_self()
+        .arg("position", "IVec2")
+        .arg("tiles", "Vec<AutoTile>")
+        .line("if (<IVec2 as VectorImpl>::x(&position) as i32) < 0 || (<IVec2 as VectorImpl>::y(&position) as i32) < 0 || <IVec2 as VectorImpl>::x(&position) as u32 >= <UVec2 as VectorImpl>::x(&self.size) as u32 || <IVec2 as VectorImpl>::y(&position) as u32 >= <UVec2 as VectorImpl>::y(&self.size) as u32 { return; };")
+        .line("self.auto_tiles[<IVec2 as VectorImpl>::x(&position) as usize + <IVec2 as VectorImpl>::y(&position) as usize * <UVec2 as VectorImpl>::x(&self.size) as usize] = tiles;");
+    Ok(())
+}
@@ -11,23 +11,64 @@ pub fn definitions(
     entity.vis("pub");
     derive_rust_object!(entity preferences.serde,);
     for entity_json in &project.defs.entities {
-        let entity_rs = code.new_struct(&entity_json.identifier);
+        let mut entity_definition = RsEntityDefinition {
+            name: definitions.names.types.insert(
+                preferences,
+                &entity_json.identifier,
+                Case::Pascal,
+                "entity",
+            ),
+            ..Default::default()
+        };
+        let entity_rs = code.new_struct(&entity_definition.name);
         entity_rs.vis("pub");
         derive_rust_object!(entity_rs preferences.serde,);
-        let mut entity_definition = RsEntityDefinition::default();
 
+        let mut members = Namespace::default();
         for field in &entity_json.field_defs {
-            let rs_type = RsFieldType::parse(field)?;
+            let rs_field = RsField::parse(preferences, definitions, project, field, &mut members)?;
             entity_rs
-                .new_field(&field.identifier, rs_type.string_type())
+                .new_field(&rs_field.name, rs_field.field_type.string_type())
                 .vis("pub");
             entity_definition
                 .fields
-                .insert(field.identifier.clone(), rs_type);
+                .insert(field.identifier.clone(), rs_field);
+        }
+
+        if preferences.validate {
+            let validate = code
+                .new_impl(&entity_definition.name)
+                .new_fn("validate")
+                .vis("pub")
+                .arg_ref_self()
+                .ret("Result<(), ValidationError>");
+            for field in &entity_json.field_defs {
+                entity_definition.fields[&field.identifier]
+                    .generate_validation(&field.identifier, validate);
+            }
+            validate.line("Ok(())");
+        }
+        let entity_type_impl = code
+            .new_impl(&entity_definition.name)
+            .impl_trait("traits::EntityType");
+        for (function, reference) in [("from_entity", "&"), ("from_entity_mut", "&mut ")] {
+            let mut match_block = Block::new("match entity");
+            match_block.line(format!(
+                "Entity::{}(entity) => Some(entity),",
+                entity_definition.name
+            ));
+            if project.defs.entities.len() > 1 {
+                match_block.line("_ => None,");
+            }
+            entity_type_impl
+                .new_fn(function)
+                .arg("entity", format!("{reference}Entity"))
+                .ret(format!("Option<{reference}Self>"))
+                .push_block(match_block);
         }
         entity
-            .new_variant(&entity_json.identifier)
-            .tuple(&entity_json.identifier);
+            .new_variant(&entity_definition.name)
+            .tuple(&entity_definition.name);
         definitions
             .entities
             .insert(entity_json.identifier.clone(), entity_definition);
@@ -48,8 +89,10 @@ pub fn definitions(
                         match_block.line("_ => unreachable!()");
                     } else {
                         for $variant in &project.defs.entities {
-                            match_block
-                                .line(format!("Self::{}(_) => {},", $variant.identifier, $line));
+                            match_block.line(format!(
+                                "Self::{}(_) => {},",
+                                definitions.entities[&$variant.identifier].name, $line
+                            ));
                         }
                     }
                     match_block
@@ -64,49 +107,134 @@ pub fn definitions(
         RenderMode::Rectangle => "RenderMode::Rectangle".to_owned(),
         RenderMode::Tile => {
             let rect = variant.tile_rect.as_ref().context("Tile render mode doesn't have tile rect!")?;
-            let tileset = variant.tileset_id.context("Tile render mode doesn't have tileset ID!")?;
-            let tile_size = definitions.tilesets.get(&tileset).context("Entity tileset not found!")?.tile_size;
-            format!(
-                "RenderMode::Tile {{ tileset: {}, tile: <UVec2 as VectorImpl>::new({} as _, {} as _), size: <UVec2 as VectorImpl>::new({} as _, {} as _) }}",
-                tileset,
-                rect.x as u32 / tile_size,
-                rect.y as u32 / tile_size,
-                rect.w as u32 / tile_size,
-                rect.h as u32 / tile_size,
-            )
+            format!("RenderMode::Tile({})", definitions.format_tile_rect(rect).context("Entity tileset not found!")?)
         },
     });
 
+    if preferences.validate {
+        let mut match_block = Block::new("match self");
+        if project.defs.entities.is_empty() {
+            match_block.line("_ => unreachable!()");
+        }
+        for entity_json in &project.defs.entities {
+            match_block.line(format!(
+                "Self::{}(entity) => entity.validate(),",
+                definitions.entities[&entity_json.identifier].name
+            ));
+        }
+        entity
+            .new_fn("validate")
+            .vis("pub")
+            .arg_ref_self()
+            .ret("Result<(), ValidationError>")
+            .push_block(match_block);
+    }
+
     Ok(())
 }
 
 pub fn layer_definition(
     preferences: &Preferences,
     definitions: &mut RsDefinitions,
+    project: &LdtkJson,
     layer_json: &LayerDefinition,
     code: &mut Scope,
     level: &mut codegen::Struct,
 ) {
-    let layer_type_name = &layer_json.identifier;
+    let layer_type_name = &definitions.names.layers[&layer_json.identifier]
+        .type_name
+        .clone();
 
     let layer_struct = code.new_struct(layer_type_name).vis("pub");
     derive_rust_object!(layer_struct preferences.serde,);
-    layer_struct.new_field("size", "UVec2").vis("pub");
-    layer_struct
-        .new_field("entities", "Vec<EntityObject>".to_owned())
-        .vis("pub");
+    super::layer_fields(layer_struct);
+    let entities_field = layer_struct.new_field("entities", "Vec<EntityObject>".to_owned());
+    if preferences.spatial_index {
+        // Only reachable through entities_mut(), which drops the index
+        let index_field =
+            layer_struct.new_field("spatial_index", "std::sync::OnceLock<EntityGrid>");
+        if preferences.serde {
+            index_field.annotation("#[serde(skip)]");
+        }
+    } else {
+        entities_field.vis("pub");
+    }
 
     super::impl_layer_trait(code, layer_type_name, layer_json);
 
-    generate_impl!(code trait "traits::Entities" for layer_type_name => {
-        fn entities(&self) -> &Vec<EntityObject> {
-            return &self.entities;
+    let entities_impl = code
+        .new_impl(layer_type_name)
+        .impl_trait("traits::Entities");
+    entities_impl
+        .new_fn("entities")
+        .arg_ref_self()
+        .ret("&Vec<EntityObject>")
+        .line("return &self.entities;");
+    let entities_mut = entities_impl
+        .new_fn("entities_mut")
+        .arg_mut_self()
+        .ret("&mut Vec<EntityObject>");
+    if preferences.spatial_index {
+        entities_mut.line("self.spatial_index.take();");
+    }
+    entities_mut.line("return &mut self.entities;");
+    if preferences.spatial_index {
+        entities_impl
+            .new_fn("spatial_index")
+            .arg_ref_self()
+            .ret("Option<&EntityGrid>")
+            .line(
+                "Some(self.spatial_index.get_or_init(|| EntityGrid::new(&self.entities, <Self as traits::Layer>::GRID_SIZE)))",
+            );
+    }
+
+    // * Typed accessors, for the entity types this layer accepts
+    use convert_case::Casing;
+    let layer_impl = code.new_impl(layer_type_name);
+    let mut functions = Namespace::new(LAYER_METHODS);
+    for entity_json in &project.defs.entities {
+        if !accepts(layer_json, entity_json) {
+            continue;
         }
+        let entity_name = &definitions.entities[&entity_json.identifier].name;
+        let function = functions.insert_name(
+            plural(&entity_name.to_case(Case::Snake)),
+            Case::Snake,
+            "entity accessor",
+        );
+        layer_impl
+            .new_fn(&function)
+            .vis("pub")
+            .arg_ref_self()
+            .ret(format!("EntitiesOf<'_, {entity_name}>"))
+            .line("traits::Entities::iter_of(self)");
+        layer_impl
+            .new_fn(&functions.insert_name(
+                format!("{function}_mut"),
+                Case::Snake,
+                "entity accessor",
+            ))
+            .vis("pub")
+            .arg_mut_self()
+            .ret(format!("EntitiesOfMut<'_, {entity_name}>"))
+            .line("traits::Entities::iter_of_mut(self)");
 
-        fnmut entities_mut(&mut self) -> &mut Vec<EntityObject> {
-            return &mut self.entities;
+        if !matches!(entity_json.limit_scope, LimitScope::PerLayer) {
+            continue;
         }
-    });
+        if let Some(always_present) =
+            is_singleton(project, entity_json, Some(&layer_json.identifier))
+        {
+            singleton_accessors(
+                layer_impl,
+                &mut functions,
+                entity_name,
+                always_present,
+                &["self".to_owned()],
+                &["self".to_owned()],
+            );
+        }
+    }
 
     generate_impl!(code trait "std::ops::Deref" for layer_type_name => {
         type Target = "Vec<EntityObject>";
@@ -127,25 +255,242 @@ pub fn layer_definition(
     // * Update definitions
     definitions
         .layers
-        .insert(layer_type_name.clone(), RsLayerDefinition::Entities);
+        .insert(layer_json.identifier.clone(), RsLayerDefinition::Entities);
 
     level
         .new_field(
-            preferences.to_case(&layer_json.identifier, Case::Snake),
+            &definitions.names.layers[&layer_json.identifier].member,
             layer_type_name,
         )
         .vis("pub");
 }
 
+/// Spatial queries over every entities layer of the level
+pub fn level_queries(definitions: &RsDefinitions, project: &LdtkJson, code: &mut Scope) {
+    let layers = project
+        .defs
+        .layers
+        .iter()
+        .filter(|layer_json| matches!(layer_json.purple_type, Type::Entities))
+        .map(|layer_json| &definitions.names.layers[&layer_json.identifier].member)
+        .collect::<Vec<_>>();
+    let each_layer = |call: &str| {
+        layers
+            .iter()
+            .map(|member| format!("traits::Entities::{call}(&self.{member}, "))
+            .collect::<Vec<_>>()
+    };
+
+    let level_impl = code.new_impl("Level");
+    for (function, params, doc) in [
+        (
+            "entities_in_rect",
+            [("start", "FVec2"), ("size", "FVec2")].as_slice(),
+            "Entities of every layer overlapping the rectangle (in pixels)",
+        ),
+        (
+            "entities_at_point",
+            [("point", "FVec2")].as_slice(),
+            "Entities of every layer containing the point (in pixels)",
+        ),
+        (
+            "entities_in_circle",
+            [("center", "FVec2"), ("radius", "f32")].as_slice(),
+            "Entities of every layer touching the circle (in pixels)",
+        ),
+    ] {
+        let query = level_impl
+            .new_fn(function)
+            .vis("pub")
+            .doc(doc)
+            .arg_ref_self();
+        for (name, param_type) in params {
+            query.arg(name, *param_type);
+        }
+        let args = params.iter().map(|(name, _)| name).join(", ");
+        query.ret("Vec<&EntityObject>").line(if layers.is_empty() {
+            "Vec::new()".to_owned()
+        } else {
+            format!(
+                "[{}].concat()",
+                each_layer(function)
+                    .iter()
+                    .map(|call| format!("{call}{args})"))
+                    .join(", ")
+            )
+        });
+    }
+    level_impl
+        .new_fn("nearest_entity")
+        .vis("pub")
+        .doc("Entity of any layer closest to the point (in pixels)")
+        .arg_ref_self()
+        .arg("point", "FVec2")
+        .ret("Option<&EntityObject>")
+        .line(if layers.is_empty() {
+            "None".to_owned()
+        } else {
+            format!(
+                "[{}].into_iter().flatten().min_by(|a, b| a.distance_to(point).total_cmp(&b.distance_to(point)))",
+                each_layer("nearest_entity")
+                    .iter()
+                    .map(|call| format!("{call}point)"))
+                    .join(", ")
+            )
+        });
+}
+
+/// Accessors on the level for entities limited to one per level
+pub fn level_singletons(definitions: &RsDefinitions, project: &LdtkJson, code: &mut Scope) {
+    let level_impl = code.new_impl("Level");
+    let mut functions = Namespace::new(LEVEL_METHODS);
+    for entity_json in &project.defs.entities {
+        if !matches!(entity_json.limit_scope, LimitScope::PerLevel) {
+            continue;
+        }
+        let Some(always_present) = is_singleton(project, entity_json, None) else {
+            continue;
+        };
+        let layers = project
+            .defs
+            .layers
+            .iter()
+            .filter(|layer_json| {
+                matches!(layer_json.purple_type, Type::Entities) && accepts(layer_json, entity_json)
+            })
+            .map(|layer_json| &definitions.names.layers[&layer_json.identifier].member)
+            .collect::<Vec<_>>();
+        if layers.is_empty() {
+            continue;
+        }
+        singleton_accessors(
+            level_impl,
+            &mut functions,
+            &definitions.entities[&entity_json.identifier].name,
+            always_present,
+            &layers
+                .iter()
+                .map(|member| format!("&self.{member}"))
+                .collect::<Vec<_>>(),
+            &layers
+                .iter()
+                .map(|member| format!("&mut self.{member}"))
+                .collect::<Vec<_>>(),
+        );
+    }
+}
+
+/// Whether the layer's tag filters let this entity in
+fn accepts(layer_json: &LayerDefinition, entity_json: &EntityDefinition) -> bool {
+    let has_tag = |tags: &[String]| entity_json.tags.iter().any(|tag| tags.contains(tag));
+    (layer_json.required_tags.is_empty() || has_tag(&layer_json.required_tags))
+        && !has_tag(&layer_json.excluded_tags)
+}
+
+/// None unless an entity is limited to one instance, then whether every level (or every
+/// instance of `layer`) really holds exactly one
+fn is_singleton(
+    project: &LdtkJson,
+    entity_json: &EntityDefinition,
+    layer: Option<&str>,
+) -> Option<bool> {
+    if entity_json.max_count != 1 {
+        return None;
+    }
+    for level in &project.levels {
+        let count = level
+            .layer_instances
+            .iter()
+            .flatten()
+            .filter(|layer_json| layer.is_none() || layer == Some(layer_json.identifier.as_str()))
+            .flat_map(|layer_json| &layer_json.entity_instances)
+            .filter(|entity| entity.identifier == entity_json.identifier)
+            .count();
+        if count != 1 {
+            eprintln!(
+                "Warning: Level {} has {count} {} entities{}, only try_ accessors generated",
+                level.identifier,
+                entity_json.identifier,
+                layer
+                    .map(|layer| format!(" in layer {layer}"))
+                    .unwrap_or_default()
+            );
+            return Some(false);
+        }
+    }
+    Some(true)
+}
+
+/// `try_name()` and `try_name_mut()` returning the only entity of a type, searched for
+/// in the given layers, and `name()` and `name_mut()` when it's always present
+fn singleton_accessors(
+    code: &mut codegen::Impl,
+    functions: &mut Namespace,
+    entity_name: &str,
+    always_present: bool,
+    layers: &[String],
+    layers_mut: &[String],
+) {
+    use convert_case::Casing;
+    let function = functions.insert_name(
+        entity_name.to_case(Case::Snake),
+        Case::Snake,
+        "entity accessor",
+    );
+    let search = |iter_of: &str, layers: &[String]| {
+        let mut search = format!("traits::Entities::{iter_of}({})", layers[0]);
+        for layer in &layers[1..] {
+            search += &format!(".chain(traits::Entities::{iter_of}({layer}))");
+        }
+        format!("{search}.next()")
+    };
+    let try_function =
+        functions.insert_name(format!("try_{function}"), Case::Snake, "entity accessor");
+    let try_function_mut = functions.insert_name(
+        format!("try_{function}_mut"),
+        Case::Snake,
+        "entity accessor",
+    );
+    code.new_fn(&try_function)
+        .vis("pub")
+        .doc(format!("The only {entity_name}, None if there is none"))
+        .arg_ref_self()
+        .ret(format!("Option<TypedEntity<'_, {entity_name}>>"))
+        .line(search("iter_of", layers));
+    code.new_fn(&try_function_mut)
+        .vis("pub")
+        .arg_mut_self()
+        .ret(format!("Option<TypedEntityMut<'_, {entity_name}>>"))
+        .line(search("iter_of_mut", layers_mut));
+    if !always_present {
+        return;
+    }
+    code.new_fn(&function)
+        .vis("pub")
+        .doc(format!(
+            "The only {entity_name}, checked when generating. Panics if it was removed"
+        ))
+        .arg_ref_self()
+        .ret(format!("TypedEntity<'_, {entity_name}>"))
+        .line(format!(
+            "self.{try_function}().expect(\"{entity_name} was removed\")"
+        ));
+    code.new_fn(&functions.insert_name(format!("{function}_mut"), Case::Snake, "entity accessor"))
+        .vis("pub")
+        .arg_mut_self()
+        .ret(format!("TypedEntityMut<'_, {entity_name}>"))
+        .line(format!(
+            "self.{try_function_mut}().expect(\"{entity_name} was removed\")"
+        ));
+}
+
 pub fn layer_instance(
+    preferences: &Preferences,
     definitions: &RsDefinitions,
     layer_rs: &mut Block,
     layer_json: &LayerInstance,
 ) -> Result<()> {
-    layer_rs.line(format!(
-        "size: <UVec2 as VectorImpl>::new({} as _, {} as _),",
-        layer_json.c_wid, layer_json.c_hei
-    ));
+    super::layer_instance_fields(layer_rs, layer_json);
     let mut entities = Block::new("entities: vec!");
     for entity in &layer_json.entity_instances {
         let definition = definitions
@@ -154,18 +499,30 @@ pub fn layer_instance(
             .context("Entity from level was not found!")?;
         let mut instance = Block::new(&format!(
             "EntityObject::new(Entity::{}({}",
-            entity.identifier, entity.identifier,
+            definition.name, definition.name,
         ));
 
         for field in &entity.field_instances {
-            let field_type = definition.fields.get(&field.identifier).context(format!(
+            let rs_field = definition.fields.get(&field.identifier).context(format!(
                 "Entity field was not found in definition ({})!",
                 &field.identifier
             ))?;
+            let location = format!(
+                "Field {} of entity {} at ({}, {}) in layer {}",
+                field.identifier,
+                entity.identifier,
+                entity.px[0],
+                entity.px[1],
+                layer_json.identifier
+            );
+            rs_field.check(&location, field.value.as_ref())?;
             instance.line(format!(
                 "{}: {},",
-                &field.identifier,
-                field_type.fmt_value(definitions, field.value.as_ref())?
+                rs_field.name,
+                rs_field
+                    .field_type
+                    .fmt_value(definitions, field.value.as_ref())
+                    .context(location)?
             ));
         }
 
@@ -176,6 +533,10 @@ pub fn layer_instance(
         ));
         entities.push_block(instance);
     }
+    entities.after(",");
     layer_rs.push_block(entities);
+    if preferences.spatial_index {
+        layer_rs.line("spatial_index: std::sync::OnceLock::new(),");
+    }
     Ok(())
 }
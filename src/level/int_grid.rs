@@ -7,53 +7,195 @@ pub fn layer_definition(
     code: &mut Scope,
     level: &mut codegen::Struct,
 ) -> Result<()> {
-    let layer_type_name = &layer_json.identifier;
+    let layer_type_name = &definitions.names.layers[&layer_json.identifier]
+        .type_name
+        .clone();
 
     // * Tiles
-    let tile_type_name = format!("{}Tile", &layer_type_name);
+    let tile_type_name = definitions.names.types.insert_name(
+        format!("{}Tile", &layer_type_name),
+        Case::Pascal,
+        "IntGrid tile enum",
+    );
     let tile_enum = code.new_enum(&tile_type_name).vis("pub");
     derive_rust_object!(tile_enum preferences.serde, Copy, Default, Hash !partial Eq, Ord);
     tile_enum.new_variant("Empty").annotation("#[default]");
 
     let mut tile_variants = std::collections::HashMap::new();
+    let mut variants = Namespace::new(&["Empty"]);
     for (index, cell_value) in layer_json.int_grid_values.iter().enumerate() {
-        let tile_name = cell_value
-            .identifier
-            .clone()
-            .unwrap_or_else(|| format!("Tile{}", index));
+        let tile_name = match &cell_value.identifier {
+            Some(identifier) => {
+                variants.insert(preferences, identifier, Case::Pascal, "IntGrid value")
+            }
+            None => variants.insert_name(format!("Tile{}", index), Case::Pascal, "IntGrid value"),
+        };
         tile_enum.new_variant(&tile_name);
         tile_variants.insert(cell_value.value, tile_name);
     }
 
+    // * Groups
+    let mut group_variants = std::collections::HashMap::new();
+    let group_type_name = if !layer_json.int_grid_values_groups.is_empty() {
+        let group_type_name = definitions.names.types.insert_name(
+            format!("{}TileGroup", &layer_type_name),
+            Case::Pascal,
+            "IntGrid group enum",
+        );
+        let group_enum = code.new_enum(&group_type_name).vis("pub");
+        derive_rust_object!(group_enum preferences.serde, Copy, Hash !partial Eq, Ord);
+        let mut variants = Namespace::default();
+        for group in &layer_json.int_grid_values_groups {
+            let group_name = match &group.identifier {
+                Some(identifier) => {
+                    variants.insert(preferences, identifier, Case::Pascal, "IntGrid group")
+                }
+                None => variants.insert_name(
+                    format!("Group{}", group.uid),
+                    Case::Pascal,
+                    "IntGrid group",
+                ),
+            };
+            group_enum.new_variant(&group_name);
+            group_variants.insert(group.uid, group_name);
+        }
+
+        let mut colors = Block::new("match self");
+        for group in &layer_json.int_grid_values_groups {
+            colors.line(format!(
+                "Self::{} => {},",
+                group_variants[&group.uid],
+                match &group.color {
+                    Some(color) => format!("Some({})", format_color(color)?),
+                    None => "None".to_owned(),
+                }
+            ));
+        }
+        code.new_impl(&group_type_name)
+            .new_fn("color")
+            .vis("pub")
+            .arg_ref_self()
+            .ret("Option<Color>")
+            .push_block(colors);
+        Some(group_type_name)
+    } else {
+        None
+    };
+
+    // * Value metadata
+    let mut values = Block::new("match self");
+    let mut from_values = Block::new("match value");
+    let mut colors = Block::new("match self");
+    let mut icons = Block::new("match self");
+    let mut groups = Block::new("match self");
+    values.line("Self::Empty => 0,");
+    from_values.line("0 => Some(Self::Empty),");
+    for cell_value in &layer_json.int_grid_values {
+        let variant = &tile_variants[&cell_value.value];
+        values.line(format!("Self::{variant} => {},", cell_value.value));
+        from_values.line(format!("{} => Some(Self::{variant}),", cell_value.value));
+        colors.line(format!(
+            "Self::{variant} => Some({}),",
+            format_color(&cell_value.color)?
+        ));
+        if let Some(tile) = &cell_value.tile {
+            icons.line(format!(
+                "Self::{variant} => Some({}),",
+                definitions
+                    .format_tile_rect(tile)
+                    .context("IntGrid value tileset was not found!")?
+            ));
+        }
+        if let Some(group) = group_variants.get(&cell_value.group_uid) {
+            groups.line(format!(
+                "Self::{variant} => Some({}::{group}),",
+                group_type_name.as_ref().unwrap()
+            ));
+        }
+    }
+    from_values.line("_ => None,");
+    colors.line("Self::Empty => None,");
+    icons.line("_ => None,");
+    groups.line("_ => None,");
+
+    let tile_impl = code.new_impl(&tile_type_name);
+    tile_impl
+        .new_fn("value")
+        .vis("pub")
+        .arg_ref_self()
+        .ret("i64")
+        .push_block(values);
+    tile_impl
+        .new_fn("from_value")
+        .vis("pub")
+        .arg("value", "i64")
+        .ret("Option<Self>")
+        .push_block(from_values);
+    tile_impl
+        .new_fn("color")
+        .vis("pub")
+        .arg_ref_self()
+        .ret("Option<Color>")
+        .push_block(colors);
+    tile_impl
+        .new_fn("icon")
+        .vis("pub")
+        .arg_ref_self()
+        .ret("Option<TileRect>")
+        .push_block(icons);
+    if let Some(group_type_name) = &group_type_name {
+        tile_impl
+            .new_fn("group")
+            .vis("pub")
+            .arg_ref_self()
+            .ret(format!("Option<{group_type_name}>"))
+            .push_block(groups);
+        tile_impl
+            .new_fn("is_in_group")
+            .vis("pub")
+            .arg_ref_self()
+            .arg("group", group_type_name)
+            .ret("bool")
+            .line("self.group() == Some(group)");
+    }
+
     let layer_struct = code.new_struct(layer_type_name).vis("pub");
     derive_rust_object!(layer_struct preferences.serde,);
-    layer_struct.new_field("size", "UVec2").vis("pub");
+    super::layer_fields(layer_struct);
     layer_struct
         .new_field("tiles", format!("Vec<{tile_type_name}>"))
         .vis("pub");
 
     if !layer_json.auto_rule_groups.is_empty() {
-        layer_struct.new_field("auto_tiles", "Vec<Vec<Tile>>");
+        super::auto_layer::auto_layer_fields(layer_struct);
     }
 
     super::impl_layer_trait(code, layer_type_name, layer_json);
     super::impl_indexable_layer(code, layer_type_name, &tile_type_name, false);
+    super::impl_editable_layer(
+        code,
+        layer_type_name,
+        &tile_type_name,
+        !layer_json.auto_rule_groups.is_empty(),
+    );
     code.new_impl(layer_type_name).impl_trait("traits::IntGrid");
 
+    tile_variants.insert(0, "Empty".to_owned());
     if !layer_json.auto_rule_groups.is_empty() {
         super::impl_auto_layer(code, layer_type_name, layer_json)?;
+        super::auto_layer::impl_rule_groups(preferences, layer_type_name, layer_json, code);
+        super::auto_layer::impl_auto_rules(definitions, layer_json, layer_json, code)?;
     }
 
     // * Update definitions
-    tile_variants.insert(0, "Empty".to_owned());
     definitions.layers.insert(
-        layer_type_name.clone(),
+        layer_json.identifier.clone(),
         RsLayerDefinition::IntGrid(RsIntGridDefinition {
             grid_size: layer_json.grid_size as u32,
             tile_variants,
             tile_enum: tile_type_name,
             auto_layer: if !layer_json.auto_rule_groups.is_empty() {
-                Some(RsAutoLayerDefinition {})
+                Some(RsAutoLayerDefinition::new(layer_json))
             } else {
                 None
             },
@@ -62,7 +204,7 @@ pub fn layer_definition(
 
     level
         .new_field(
-            preferences.to_case(&layer_json.identifier, Case::Snake),
+            &definitions.names.layers[&layer_json.identifier].member,
             layer_type_name,
         )
         .vis("pub");
@@ -76,7 +218,7 @@ pub fn layer_instance(
     layer_rs: &mut Block,
     layer_json: &LayerInstance,
 ) -> Result<()> {
-    layer_rs.line(format!("size: <UVec2 as VectorImpl>::new({} as _, {} as _),", layer_json.c_wid, layer_json.c_hei));
+    super::layer_instance_fields(layer_rs, layer_json);
     let mut tiles = Block::new("tiles: vec!");
     for tile in &layer_json.int_grid_csv {
         tiles.line(format!(
@@ -87,43 +229,8 @@ pub fn layer_instance(
     }
     tiles.after(",");
     layer_rs.push_block(tiles);
-    if definition.auto_layer.is_some() {
-        let tileset_id = layer_json
-            .tileset_def_uid
-            .context("Tileset UID is not present in autotiled int grid!")?;
-        let tileset = definitions
-            .tilesets
-            .get(&tileset_id)
-            .context("Tileset from autotiled int grid was not found!")?;
-        let mut tiles = vec![Vec::new(); layer_json.c_wid as usize * layer_json.c_hei as usize];
-        for tile in &layer_json.auto_layer_tiles {
-            let tile_pos = (
-                tile.px[0] as usize / definition.grid_size as usize,
-                tile.px[1] as usize / definition.grid_size as usize,
-            );
-            let tileset_tile = (
-                tile.src[0] as u32 / tileset.tile_size,
-                tile.src[1] as u32 / tileset.tile_size,
-            );
-            tiles[tile_pos.0 + tile_pos.1 * layer_json.c_wid as usize].push(format!(
-                "Tile::new(<UVec2 as VectorImpl>::new({} as _, {} as _), {})",
-                tileset_tile.0,
-                tileset_tile.1,
-                match tile.f {
-                    0 => "FlipMode::None",
-                    1 => "FlipMode::Horizontal",
-                    2 => "FlipMode::Vertical",
-                    3 => "FlipMode::Both",
-                    _ => bail!("Invalid flip mode: {}", tile.f),
-                }
-            ));
-        }
-        let mut auto_tiles = Block::new("auto_tiles: vec!");
-        for tiles in tiles {
-            auto_tiles.line(format!("vec![{}],", tiles.join(", ")));
-        }
-        auto_tiles.after(",");
-        layer_rs.push_block(auto_tiles);
+    if let Some(auto_layer) = &definition.auto_layer {
+        super::auto_layer::auto_tiles(auto_layer, definitions, layer_rs, layer_json)?;
     }
     Ok(())
 }
@@ -1,3 +1,4 @@
+pub mod auto_layer;
 pub mod entities;
 pub mod int_grid;
 pub mod tiles;
@@ -25,6 +26,10 @@ pub fn generate_levels(
     level.new_field("world_depth", "i64").vis("pub");
     level.new_field("world_x", "i64").vis("pub");
     level.new_field("world_y", "i64").vis("pub");
+    level
+        .new_field("fields", "LevelFields")
+        .vis("pub")
+        .doc("Custom fields of the level");
 
     code.raw("/* --- Layers --- */");
     for layer_json in &project.defs.layers {
@@ -35,34 +40,272 @@ pub fn generate_levels(
             Type::Tiles => {
                 tiles::layer_definition(preferences, definitions, layer_json, code, &mut level)?
             }
-            Type::AutoLayer => println!("TODO: Auto layer"),
-            Type::Entities => {
-                entities::layer_definition(preferences, definitions, layer_json, code, &mut level)
+            Type::AutoLayer => auto_layer::layer_definition(
+                preferences,
+                definitions,
+                project,
+                layer_json,
+                code,
+                &mut level,
+            )?,
+            Type::Entities => entities::layer_definition(
+                preferences,
+                definitions,
+                project,
+                layer_json,
+                code,
+                &mut level,
+            ),
+        }
+    }
+
+    generate_level_fields(preferences, definitions, project, code)?;
+
+    code.raw("/* --- Level --- */");
+    code.push_struct(level);
+    generate_layer_enums(definitions, project, code);
+    generate_level_coordinates(project, code);
+    entities::level_queries(definitions, project, code);
+    entities::level_singletons(definitions, project, code);
+    if preferences.validate {
+        generate_level_validation(definitions, project, code);
+    }
+    generate_world(preferences, definitions, code, project)?;
+    Ok(())
+}
+
+/// LayerRef and LayerMut, to walk the layers of a level in draw order
+fn generate_layer_enums(definitions: &RsDefinitions, project: &LdtkJson, code: &mut Scope) {
+    code.raw("/* --- Layer enums --- */");
+    // LDtk lists layers from top to bottom
+    let layers = project
+        .defs
+        .layers
+        .iter()
+        .rev()
+        .filter_map(|layer_json| {
+            Some((
+                layer_json,
+                &definitions.names.layers[&layer_json.identifier],
+                definitions.layers.get(&layer_json.identifier)?,
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    for (enum_name, reference, derives) in [
+        ("LayerRef", "&'a ", ["Clone", "Copy", "Debug"].as_slice()),
+        ("LayerMut", "&'a mut ", ["Debug"].as_slice()),
+    ] {
+        let layer_enum = code
+            .new_enum(enum_name)
+            .vis("pub")
+            .generic("'a")
+            .doc("Any layer of a level");
+        for derive in derives {
+            layer_enum.derive(derive);
+        }
+        for (_, names, _) in &layers {
+            layer_enum
+                .new_variant(&names.type_name)
+                .tuple(&format!("{reference}{}", names.type_name));
+        }
+    }
+
+    // * Accessors for generic code
+    let layer_impl = code.new_impl("LayerRef").generic("'a").target_generic("'a");
+    macro_rules! generate_layer_fn {
+        ($fn: literal -> $ret: literal $(, $arg: literal: $arg_type: literal)*; $line: expr) => {
+            let mut match_block = Block::new("match self");
+            for (layer_json, names, definition) in &layers {
+                match_block.line(format!("Self::{}(layer) => {},", names.type_name, $line(layer_json, names, definition)));
             }
+            layer_impl
+                .new_fn($fn)
+                .vis("pub")
+                .arg_ref_self()
+                $(.arg($arg, $arg_type))*
+                .ret($ret)
+                .push_block(match_block);
+        };
+    }
+    generate_layer_fn!("identifier" -> "&'static str"; |layer_json: &LayerDefinition, _, _| format!("{:?}", layer_json.identifier));
+    generate_layer_fn!("size" -> "UVec2"; |_, _, _| "traits::Layer::size(*layer)");
+    generate_layer_fn!("grid_size" -> "UVec2"; |_, _, _| "traits::Layer::grid_size(*layer)");
+    generate_layer_fn!("visible" -> "bool"; |_, _, _| "traits::Layer::visible(*layer)");
+    generate_layer_fn!("opacity" -> "f32"; |_, _, _| "traits::Layer::opacity(*layer)");
+    generate_layer_fn!("total_offset" -> "IVec2"; |_, _, _| "traits::Layer::total_offset(*layer)");
+    generate_layer_fn!("parallax_factor" -> "FVec2"; |_, names: &RsLayerNames, _| format!("<{} as traits::Layer>::parallax_factor()", names.type_name));
+    generate_layer_fn!("grid_to_px" -> "IVec2", "cell": "IVec2"; |_, _, _| "traits::Layer::grid_to_px(*layer, cell)");
+    generate_layer_fn!("px_to_grid" -> "IVec2", "position": "FVec2"; |_, _, _| "traits::Layer::px_to_grid(*layer, position)");
+    generate_layer_fn!("apply_parallax" -> "FVec2", "position": "FVec2", "camera": "FVec2"; |_, _, _| "traits::Layer::apply_parallax(*layer, position, camera)");
+    generate_layer_fn!("remove_parallax" -> "FVec2", "position": "FVec2", "camera": "FVec2"; |_, _, _| "traits::Layer::remove_parallax(*layer, position, camera)");
+    generate_layer_fn!("tileset_id" -> "Option<TilesetID>"; |_, _, definition: &RsLayerDefinition| match definition {
+        RsLayerDefinition::Tiles(_) => "Some(traits::Tiles::tileset_id(*layer))",
+        RsLayerDefinition::IntGrid(RsIntGridDefinition { auto_layer: Some(_), .. }) | RsLayerDefinition::AutoLayer(_) => "Some(traits::AutoLayer::tileset_id(*layer))",
+        _ => "None",
+    });
+    generate_layer_fn!("tiles" -> "CellTiles<'a>", "position": "IVec2"; |_, _, definition: &RsLayerDefinition| match definition {
+        RsLayerDefinition::Tiles(_) => "CellTiles::Tiles(traits::IndexableLayer::get(*layer, position).unwrap_or_default().iter())",
+        RsLayerDefinition::IntGrid(RsIntGridDefinition { auto_layer: Some(_), .. }) | RsLayerDefinition::AutoLayer(_) => "CellTiles::AutoTiles(traits::AutoLayer::autotiles_at(*layer, position).iter())",
+        _ => "CellTiles::default()",
+    });
+    generate_layer_fn!("entities" -> "&'a [EntityObject]"; |_, _, definition: &RsLayerDefinition| match definition {
+        RsLayerDefinition::Entities => "&layer.entities",
+        _ => "&[]",
+    });
+
+    let mut as_ref = Block::new("match self");
+    for (_, names, _) in &layers {
+        as_ref.line(format!(
+            "Self::{0}(layer) => LayerRef::{0}(layer),",
+            names.type_name
+        ));
+    }
+    code.new_impl("LayerMut")
+        .generic("'a")
+        .target_generic("'a")
+        .new_fn("as_ref")
+        .vis("pub")
+        .arg_ref_self()
+        .ret("LayerRef<'_>")
+        .push_block(as_ref);
+
+    // * Iteration
+    let level_impl = code.new_impl("Level");
+    for (function, enum_name, reference, self_arg) in [
+        ("layers", "LayerRef", "&", false),
+        ("layers_mut", "LayerMut", "&mut ", true),
+    ] {
+        let layers_fn = level_impl
+            .new_fn(function)
+            .vis("pub")
+            .doc("Layers in the order LDtk draws them, from bottom to top")
+            .ret(format!("impl Iterator<Item = {enum_name}<'_>>"));
+        if self_arg {
+            layers_fn.arg_mut_self();
+        } else {
+            layers_fn.arg_ref_self();
+        }
+        layers_fn.line(format!(
+            "[{}].into_iter()",
+            layers
+                .iter()
+                .map(|(_, names, _)| format!(
+                    "{enum_name}::{}({reference}self.{})",
+                    names.type_name, names.member
+                ))
+                .join(", ")
+        ));
+    }
+}
+
+/// Conversions between level pixels and world pixels. Levels of linear worlds have no world
+/// position (LDtk sets world_x and world_y to -1), so the conversions are None there
+fn generate_level_coordinates(project: &LdtkJson, code: &mut Scope) {
+    let linear = matches!(
+        project.world_layout,
+        Some(WorldLayout::LinearHorizontal | WorldLayout::LinearVertical)
+    );
+    let level_impl = code.new_impl("Level");
+    for (function, doc, operator) in [
+        (
+            "level_to_world",
+            "World position of a point in level pixels, None in linear worlds",
+            '+',
+        ),
+        (
+            "world_to_level",
+            "Position in level pixels of a point of the world, None in linear worlds",
+            '-',
+        ),
+    ] {
+        let function = level_impl
+            .new_fn(function)
+            .vis("pub")
+            .doc(doc)
+            .arg_ref_self()
+            .arg("position", "FVec2")
+            .ret("Option<FVec2>");
+        if linear {
+            function.line("let _ = position;").line("None");
+        } else {
+            function
+                .line("Some(<FVec2 as VectorImpl>::new(")
+                .line(format!(
+                    "    (<FVec2 as VectorImpl>::x(&position) as f64 {operator} self.world_x as f64) as _,"
+                ))
+                .line(format!(
+                    "    (<FVec2 as VectorImpl>::y(&position) as f64 {operator} self.world_y as f64) as _,"
+                ))
+                .line("))");
         }
     }
+}
 
+fn generate_level_fields(
+    preferences: &Preferences,
+    definitions: &mut RsDefinitions,
+    project: &LdtkJson,
+    code: &mut Scope,
+) -> Result<()> {
+    code.raw("/* --- Level Fields --- */");
+    let fields = code
+        .new_struct("LevelFields")
+        .vis("pub")
+        .doc("Custom fields of levels in this LDTK project");
+    derive_rust_object!(fields preferences.serde,);
+
+    let mut members = Namespace::default();
     for field in &project.defs.level_fields {
-        let rs_type = RsFieldType::parse(field)?;
-        level
-            .new_field(
-                preferences.to_case(&field.identifier, Case::Snake),
-                rs_type.string_type(),
-            )
+        let rs_field = RsField::parse(preferences, definitions, project, field, &mut members)?;
+        fields
+            .new_field(&rs_field.name, rs_field.field_type.string_type())
             .vis("pub");
 
         definitions
             .level
             .fields
-            .insert(field.identifier.clone(), rs_type);
+            .insert(field.identifier.clone(), rs_field);
     }
 
-    code.raw("/* --- Level --- */");
-    code.push_struct(level);
-    generate_world(preferences, definitions, code, project)?;
+    if preferences.validate {
+        let validate = code
+            .new_impl("LevelFields")
+            .new_fn("validate")
+            .vis("pub")
+            .arg_ref_self()
+            .ret("Result<(), ValidationError>");
+        for field in &project.defs.level_fields {
+            definitions.level.fields[&field.identifier]
+                .generate_validation(&field.identifier, validate);
+        }
+        validate.line("Ok(())");
+    }
     Ok(())
 }
 
+fn generate_level_validation(definitions: &RsDefinitions, project: &LdtkJson, code: &mut Scope) {
+    let validate = code
+        .new_impl("Level")
+        .new_fn("validate")
+        .vis("pub")
+        .doc("Check level fields and all entities against LDtk field constraints")
+        .arg_ref_self()
+        .ret("Result<(), ValidationError>");
+    validate.line("self.fields.validate()?;");
+    for layer_json in &project.defs.layers {
+        if matches!(layer_json.purple_type, Type::Entities) {
+            let mut entities = Block::new(&format!(
+                "for object in &self.{}.entities",
+                definitions.names.layers[&layer_json.identifier].member
+            ));
+            entities.line("object.entity.validate()?;");
+            validate.push_block(entities);
+        }
+    }
+    validate.line("Ok(())");
+}
+
 // * ----------------------------------- Instance ----------------------------------- * //
 pub fn generate_world(
     preferences: &Preferences,
@@ -95,9 +338,7 @@ pub fn generate_world(
         if let Some(path) = &tileset.rel_path {
             code.raw(format!(
                 "pub const {}: Tileset = Tileset::new({}, {:?});",
-                preferences.to_case(&tileset.identifier, Case::UpperSnake),
-                tileset.uid,
-                path
+                definitions.names.tilesets[&tileset.uid], tileset.uid, path
             ));
         }
     }
@@ -117,7 +358,7 @@ pub fn generate_world(
 
     for level in &project.levels {
         world
-            .new_field(preferences.to_case(&level.identifier, Case::Snake), "Level")
+            .new_field(&definitions.names.levels[&level.identifier], "Level")
             .vis("pub");
     }
 
@@ -130,8 +371,7 @@ pub fn generate_world(
             for (index, level) in project.levels.iter().enumerate() {
                 match_block.line(format!(
                     $fmt,
-                    index,
-                    &preferences.to_case(&level.identifier, Case::Snake)
+                    index, definitions.names.levels[&level.identifier]
                 ));
             }
             match_block.line("_ => None,");
@@ -171,7 +411,7 @@ pub fn generate_world(
     for level_json in &project.levels {
         let mut level_rs = Block::new(&format!(
             "{}: Level",
-            preferences.to_case(&level_json.identifier, Case::Snake)
+            definitions.names.levels[&level_json.identifier]
         ));
         level_rs.after(",");
         level_rs.line(format!(
@@ -191,10 +431,10 @@ pub fn generate_world(
             // TODO: External levels
             .context("External levels are not supported yet.")?
         {
+            let layer_names = &definitions.names.layers[&layer_json.identifier];
             let mut layer_rs = Block::new(&format!(
                 "{}: {}",
-                preferences.to_case(&layer_json.identifier, Case::Snake),
-                &layer_json.identifier
+                layer_names.member, layer_names.type_name
             ));
             layer_rs.after(",");
             if !definitions.layers.contains_key(&layer_json.identifier) {
@@ -207,15 +447,21 @@ pub fn generate_world(
                 RsLayerDefinition::Tiles(definition) => {
                     tiles::layer_instance(definition, definitions, &mut layer_rs, layer_json)?
                 }
+                RsLayerDefinition::AutoLayer(definition) => {
+                    auto_layer::layer_instance(definition, definitions, &mut layer_rs, layer_json)?
+                }
                 RsLayerDefinition::Entities => {
-                    entities::layer_instance(definitions, &mut layer_rs, layer_json)?
+                    entities::layer_instance(preferences, definitions, &mut layer_rs, layer_json)
+                        .context(format!("In level {}", level_json.identifier))?
                 }
             }
             level_rs.push_block(layer_rs);
         }
 
+        let mut fields_rs = Block::new("fields: LevelFields");
+        fields_rs.after(",");
         for field in &level_json.field_instances {
-            let field_type = definitions
+            let rs_field = definitions
                 .level
                 .fields
                 .get(&field.identifier)
@@ -223,12 +469,21 @@ pub fn generate_world(
                     "Level field was not found in definition ({})!",
                     &field.identifier
                 ))?;
-            level_rs.line(format!(
+            let location = format!(
+                "Field {} of level {}",
+                field.identifier, level_json.identifier
+            );
+            rs_field.check(&location, field.value.as_ref())?;
+            fields_rs.line(format!(
                 "{}: {},",
-                preferences.to_case(&field.identifier, Case::Snake),
-                field_type.fmt_value(definitions, field.value.as_ref())?
+                rs_field.name,
+                rs_field
+                    .field_type
+                    .fmt_value(definitions, field.value.as_ref())
+                    .context(location)?
             ));
         }
+        level_rs.push_block(fields_rs);
         world.push_block(level_rs);
     }
 
@@ -241,6 +496,33 @@ pub fn generate_world(
     Ok(())
 }
 
+/// Members shared by all layer structs
+pub fn layer_fields(layer_struct: &mut codegen::Struct) {
+    layer_struct.new_field("size", "UVec2").vis("pub");
+    layer_struct.new_field("visible", "bool").vis("pub");
+    layer_struct
+        .new_field("opacity", "f32")
+        .vis("pub")
+        .doc("Opacity of this instance (0 to 1)");
+    layer_struct
+        .new_field("instance_offset", "IVec2")
+        .vis("pub")
+        .doc("Pixel offset of this instance, added to the offset of the definition");
+}
+
+pub fn layer_instance_fields(layer_rs: &mut Block, layer_json: &LayerInstance) {
+    layer_rs.line(format!(
+        "size: <UVec2 as VectorImpl>::new({} as _, {} as _),",
+        layer_json.c_wid, layer_json.c_hei
+    ));
+    layer_rs.line(format!("visible: {},", layer_json.visible));
+    layer_rs.line(format!("opacity: {:?},", layer_json.opacity as f32));
+    layer_rs.line(format!(
+        "instance_offset: <IVec2 as VectorImpl>::new({} as _, {} as _),",
+        layer_json.px_offset_x, layer_json.px_offset_y
+    ));
+}
+
 pub fn impl_layer_trait(code: &mut Scope, layer_type_name: &str, layer_json: &LayerDefinition) {
     generate_impl!(code trait "traits::Layer" for layer_type_name => {
         const GRID_SIZE: u32 = format!("{}", layer_json.grid_size);
@@ -254,6 +536,18 @@ pub fn impl_layer_trait(code: &mut Scope, layer_type_name: &str, layer_json: &La
         fn size(&self) -> UVec2 {
             return self.size;
         }
+
+        fn visible(&self) -> bool {
+            return self.visible;
+        }
+
+        fn opacity(&self) -> f32 {
+            return self.opacity;
+        }
+
+        fn instance_offset(&self) -> IVec2 {
+            return self.instance_offset;
+        }
     });
 }
 
@@ -261,20 +555,20 @@ pub fn impl_indexable_layer(
     code: &mut Scope,
     layer_type_name: &str,
     tile_type_name: &str,
-    flatten: bool,
+    stacked: bool,
 ) {
-    if flatten {
+    if stacked {
         generate_impl!(code trait "traits::IndexableLayer" for layer_type_name => {
             type Tile = tile_type_name;
 
             fn get(&self, position: IVec2) -> Option<&Self::Tile> {
                 if (<IVec2 as VectorImpl>::x(&position) as i32) < 0 || (<IVec2 as VectorImpl>::y(&position) as i32) < 0 || <IVec2 as VectorImpl>::x(&position) as u32 >= <UVec2 as VectorImpl>::x(&self.size) as u32 || <IVec2 as VectorImpl>::y(&position) as u32 >= <UVec2 as VectorImpl>::y(&self.size) as u32 { return None; };
-                return self.tiles.get(<IVec2 as VectorImpl>::x(&position) as usize + <IVec2 as VectorImpl>::y(&position) as usize * <UVec2 as VectorImpl>::x(&self.size) as usize).and_then(|tile| tile.as_ref());
+                return self.tiles.get(<IVec2 as VectorImpl>::x(&position) as usize + <IVec2 as VectorImpl>::y(&position) as usize * <UVec2 as VectorImpl>::x(&self.size) as usize).map(TileStack::as_slice);
             }
 
             fnmut get_mut(&mut self, position: IVec2) -> Option<&mut Self::Tile> {
                 if (<IVec2 as VectorImpl>::x(&position) as i32) < 0 || (<IVec2 as VectorImpl>::y(&position) as i32) < 0 || <IVec2 as VectorImpl>::x(&position) as u32 >= <UVec2 as VectorImpl>::x(&self.size) as u32 || <IVec2 as VectorImpl>::y(&position) as u32 >= <UVec2 as VectorImpl>::y(&self.size) as u32 { return None; };
-                return self.tiles.get_mut(<IVec2 as VectorImpl>::x(&position) as usize + <IVec2 as VectorImpl>::y(&position) as usize * <UVec2 as VectorImpl>::x(&self.size) as usize).and_then(|tile| tile.as_mut());
+                return self.tiles.get_mut(<IVec2 as VectorImpl>::x(&position) as usize + <IVec2 as VectorImpl>::y(&position) as usize * <UVec2 as VectorImpl>::x(&self.size) as usize).map(TileStack::as_mut_slice);
             }
         });
     } else {
@@ -308,6 +602,47 @@ pub fn impl_indexable_layer(
     });
 }
 
+/// Editing of the `tiles` field, updating auto tiles with `auto_rules`
+pub fn impl_editable_layer(
+    code: &mut Scope,
+    layer_type_name: &str,
+    cell_type_name: &str,
+    auto_rules: bool,
+) {
+    let editable = code
+        .new_impl(layer_type_name)
+        .impl_trait("traits::EditableLayer");
+    editable.associate_type("Cell", cell_type_name);
+    editable
+        .new_fn("cells")
+        .arg_ref_self()
+        .ret("&[Self::Cell]")
+        .line("&self.tiles");
+    editable
+        .new_fn("cells_mut")
+        .arg_mut_self()
+        .ret("&mut [Self::Cell]")
+        .line("&mut self.tiles");
+    let replace_cells = editable
+        .new_fn("replace_cells")
+        .arg_mut_self()
+        .arg("size", "UVec2")
+        .arg("cells", "Vec<Self::Cell>")
+        .line("self.size = size;")
+        .line("self.tiles = cells;");
+    if auto_rules {
+        replace_cells
+            .line("self.auto_tiles = vec![Vec::new(); self.tiles.len()];")
+            .line("traits::AutoRules::recompute_autotiles(self, <IVec2 as VectorImpl>::new(0 as _, 0 as _), size);");
+        editable
+            .new_fn("cells_changed")
+            .arg_mut_self()
+            .arg("start", "IVec2")
+            .arg("size", "UVec2")
+            .line("traits::AutoRules::recompute_autotiles(self, start, size);");
+    }
+}
+
 pub fn impl_auto_layer(
     code: &mut Scope,
     layer_type_name: &str,
@@ -316,9 +651,21 @@ pub fn impl_auto_layer(
     generate_impl!(code trait "traits::AutoLayer" for layer_type_name => {
         const TILESET_ID: TilesetID = format!("{}", layer_json.tileset_def_uid.context("No tileset UID in auto layer!")?);
 
-        fn get_autotile(&self, position: IVec2) -> Vec<Tile> {
-            if (<IVec2 as VectorImpl>::x(&position) as i32) < 0 || (<IVec2 as VectorImpl>::y(&position) as i32) < 0 || <IVec2 as VectorImpl>::x(&position) as u32 >= <UVec2 as VectorImpl>::x(&self.size) as u32 || <IVec2 as VectorImpl>::y(&position) as u32 >= <UVec2 as VectorImpl>::y(&self.size) as u32 { return Vec::new(); };
-            return self.auto_tiles.get(<IVec2 as VectorImpl>::x(&position) as usize + <IVec2 as VectorImpl>::y(&position) as usize * <UVec2 as VectorImpl>::x(&self.size) as usize).cloned().unwrap_or_default();
+        fn override_tileset(&self) -> Option<TilesetID> {
+            return self.override_tileset;
+        }
+
+        fn seed(&self) -> u32 {
+            return self.seed;
+        }
+
+        fn optional_rules(&self) -> &[u32] {
+            return &self.optional_rules;
+        }
+
+        fn autotiles_at(&self, position: IVec2) -> &[AutoTile] {
+            if (<IVec2 as VectorImpl>::x(&position) as i32) < 0 || (<IVec2 as VectorImpl>::y(&position) as i32) < 0 || <IVec2 as VectorImpl>::x(&position) as u32 >= <UVec2 as VectorImpl>::x(&self.size) as u32 || <IVec2 as VectorImpl>::y(&position) as u32 >= <UVec2 as VectorImpl>::y(&self.size) as u32 { return &[]; };
+            return self.auto_tiles.get(<IVec2 as VectorImpl>::x(&position) as usize + <IVec2 as VectorImpl>::y(&position) as usize * <UVec2 as VectorImpl>::x(&self.size) as usize).map_or(&[], Vec::as_slice);
         }
     });
     Ok(())
@@ -7,34 +7,45 @@ pub fn layer_definition(
     code: &mut Scope,
     level: &mut codegen::Struct,
 ) -> Result<()> {
-    let layer_type_name = &layer_json.identifier;
+    let layer_type_name = &definitions.names.layers[&layer_json.identifier]
+        .type_name
+        .clone();
 
     let layer_struct = code.new_struct(layer_type_name).vis("pub");
     derive_rust_object!(layer_struct preferences.serde,);
-    layer_struct.new_field("size", "UVec2").vis("pub");
+    super::layer_fields(layer_struct);
     layer_struct
-        .new_field("tiles", "Vec<Option<Tile>>".to_owned())
+        .new_field("tiles", "Vec<TileStack>".to_owned())
         .vis("pub");
+    layer_struct
+        .new_field("override_tileset", "Option<TilesetID>")
+        .vis("pub")
+        .doc("Tileset used instead of the one of the definition");
 
     super::impl_layer_trait(code, layer_type_name, layer_json);
-    super::impl_indexable_layer(code, layer_type_name, "Tile", true);
-    code.new_impl(layer_type_name)
-        .impl_trait("traits::Tiles")
-        .associate_const(
-            "TILESET_ID",
-            "TilesetID",
-            format!(
-                "{}",
-                layer_json
-                    .tileset_def_uid
-                    .context("Tiles layer doesn't have a tileset ID!")?
-            ),
-            "",
-        );
+    super::impl_indexable_layer(code, layer_type_name, "[Tile]", true);
+    super::impl_editable_layer(code, layer_type_name, "TileStack", false);
+    let tiles_impl = code.new_impl(layer_type_name).impl_trait("traits::Tiles");
+    tiles_impl
+        .new_fn("override_tileset")
+        .arg_ref_self()
+        .ret("Option<TilesetID>")
+        .line("self.override_tileset");
+    tiles_impl.associate_const(
+        "TILESET_ID",
+        "TilesetID",
+        format!(
+            "{}",
+            layer_json
+                .tileset_def_uid
+                .context("Tiles layer doesn't have a tileset ID!")?
+        ),
+        "",
+    );
 
     // * Update definitions
     definitions.layers.insert(
-        layer_type_name.clone(),
+        layer_json.identifier.clone(),
         RsLayerDefinition::Tiles(RsTilesDefinition {
             grid_size: layer_json.grid_size as u32,
         }),
@@ -42,7 +53,7 @@ pub fn layer_definition(
 
     level
         .new_field(
-            preferences.to_case(&layer_json.identifier, Case::Snake),
+            &definitions.names.layers[&layer_json.identifier].member,
             layer_type_name,
         )
         .vis("pub");
@@ -59,40 +70,30 @@ pub fn layer_instance(
     let tileset_id = layer_json
         .tileset_def_uid
         .context("Tileset UID is not present in autotiled int grid!")?;
-    let tileset = definitions
-        .tilesets
-        .get(&tileset_id)
-        .context("Tileset from autotiled int grid was not found!")?;
 
-    layer_rs.line(format!("size: <UVec2 as VectorImpl>::new({} as _, {} as _),", layer_json.c_wid, layer_json.c_hei));
-    let mut tiles = vec!["None".to_owned(); layer_json.c_wid as usize * layer_json.c_hei as usize];
+    super::layer_instance_fields(layer_rs, layer_json);
+    let mut tiles = vec![Vec::new(); layer_json.c_wid as usize * layer_json.c_hei as usize];
     for tile in &layer_json.grid_tiles {
-        let tile_pos = (
-            tile.px[0] as usize / definition.grid_size as usize,
-            tile.px[1] as usize / definition.grid_size as usize,
-        );
-        let tileset_tile = (
-            tile.src[0] as u32 / tileset.tile_size,
-            tile.src[1] as u32 / tileset.tile_size,
-        );
-        tiles[tile_pos.0 + tile_pos.1 * layer_json.c_wid as usize] = format!(
-            "Some(Tile::new(<UVec2 as VectorImpl>::new({} as _, {} as _), {}))",
-            tileset_tile.0,
-            tileset_tile.1,
-            match tile.f {
-                0 => "FlipMode::None",
-                1 => "FlipMode::Horizontal",
-                2 => "FlipMode::Vertical",
-                3 => "FlipMode::Both",
-                _ => bail!("Invalid flip mode: {}", tile.f),
-            }
-        );
+        let ((x, y), tile) =
+            definitions.format_tile(tileset_id, definition.grid_size, layer_json, tile)?;
+        // Stacked tiles are listed from bottom to top
+        if let Some(cell) = tiles.get_mut((x + y * layer_json.c_wid) as usize) {
+            cell.push(tile);
+        }
     }
     let mut tiles_field = Block::new("tiles: vec!");
     for tiles in tiles {
-        tiles_field.line(format!("{},", tiles));
+        tiles_field.line(match tiles.len() {
+            0 => "TileStack::Empty,".to_owned(),
+            1 => format!("TileStack::Single({}),", tiles[0]),
+            _ => format!("TileStack::Stacked(vec![{}]),", tiles.join(", ")),
+        });
     }
     tiles_field.after(",");
     layer_rs.push_block(tiles_field);
+    layer_rs.line(format!(
+        "override_tileset: {:?},",
+        layer_json.override_tileset_uid
+    ));
     Ok(())
 }
@@ -1,10 +1,12 @@
 pub mod definitions;
+#[allow(clippy::doc_lazy_continuation)]
 pub mod schema;
 use clap::Parser;
 use definitions::*;
 use std::path::PathBuf;
 
 pub mod level;
+pub mod naming;
 pub mod typedefs;
 
 /// Convert LDTK Project to Rust code
@@ -36,22 +38,38 @@ struct Args {
     /// ldtk_module::ColorImpl has to be implemented for this type
     #[arg(long)]
     color: Option<String>,
+
+    /// Generate validate() methods for entities and levels, checking LDtk field constraints
+    /// Fields with a regex constraint require the `regex` crate
+    #[arg(long, default_value_t = false)]
+    validate: bool,
+
+    /// Keep a uniform grid over each entities layer, so that spatial queries don't scan every
+    /// entity. The `entities` field of layers becomes private, use entities_mut() or Deref
+    #[arg(long, default_value_t = false)]
+    spatial_index: bool,
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
+    let project = serde_json::from_str::<schema::LdtkJson>(
+        &std::fs::read_to_string(&args.path).context("Failed to load project file!")?,
+    )
+    .context("Failed to deserialize project file!")?;
+
     let preferences = Preferences {
         preserve_case: args.preserve_case,
         serde: args.serde,
         vector: args.vector,
         color: args.color,
+        validate: args.validate,
+        spatial_index: args.spatial_index,
+        identifier_style: project.identifier_style.clone(),
+    };
+    let mut definitions = RsDefinitions {
+        names: naming::RsNames::new(&preferences, &project),
+        ..Default::default()
     };
-    let mut definitions = RsDefinitions::default();
-
-    let project = serde_json::from_str::<schema::LdtkJson>(
-        &std::fs::read_to_string(&args.path).context("Failed to load project file!")?,
-    )
-    .context("Failed to deserialize project file!")?;
 
     let mut scope = Scope::new();
     typedefs::generate_defs(&preferences, &mut definitions, &project, &mut scope)
@@ -0,0 +1,355 @@
+use crate::definitions::*;
+use std::collections::{HashMap, HashSet};
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Keywords that can't be used as raw identifiers
+const NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+/// Turn anything into a valid rust identifier
+pub fn sanitize_identifier(identifier: &str) -> String {
+    let mut name = identifier
+        .chars()
+        .map(|char| {
+            if char.is_alphanumeric() || char == '_' {
+                char
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    if name.is_empty() || name == "_" {
+        name.push('_');
+    }
+    if name.starts_with(|char: char| char.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    if NON_RAW_KEYWORDS.contains(&name.as_str()) {
+        name.push('_');
+    } else if KEYWORDS.contains(&name.as_str()) {
+        name.insert_str(0, "r#");
+    }
+    name
+}
+
+/// Types and modules of the header and fixed generated types,
+/// and std types and traits the generated code relies on
+pub const RESERVED_TYPES: &[&str] = &[
+    "ColorImpl",
+    "VectorImpl",
+    "UVec2",
+    "IVec2",
+    "FVec2",
+    "Color",
+    "NonEmpty",
+    "TilesetID",
+    "Tileset",
+    "Tile",
+    "TileStack",
+    "AutoTile",
+    "CellTiles",
+    "TileRect",
+    "FlipMode",
+    "AutoRuleGroup",
+    "AutoRule",
+    "RuleChecker",
+    "AutoRuleTile",
+    "EntityObject",
+    "RenderMode",
+    "EntityRef",
+    "TypedEntity",
+    "TypedEntityMut",
+    "EntitiesOf",
+    "EntitiesOfMut",
+    "EntityGrid",
+    "CellRect",
+    "RayHit",
+    "OutOfBounds",
+    "OutsideLayer",
+    "CellBlock",
+    "Neighbourhood",
+    "CornerCutting",
+    "PathBuffer",
+    "Region",
+    "RegionId",
+    "RegionMap",
+    "ValidationError",
+    "WorldLayout",
+    "Entity",
+    "Level",
+    "LayerRef",
+    "LayerMut",
+    "LevelFields",
+    "World",
+    "math",
+    "traits",
+    "Option",
+    "Result",
+    "Vec",
+    "String",
+    "Box",
+    "Some",
+    "None",
+    "Ok",
+    "Err",
+    "Sized",
+    "Clone",
+    "Copy",
+    "Default",
+    "PartialEq",
+    "Eq",
+    "PartialOrd",
+    "Ord",
+    "Fn",
+    "From",
+    "TryFrom",
+    "Iterator",
+    "IntoIterator",
+    "ExactSizeIterator",
+    "bool",
+    "i32",
+    "i64",
+    "u32",
+    "f32",
+    "usize",
+    "str",
+];
+
+/// Identifiers of one rust scope (types of the module, members of a struct, variants of an enum)
+#[derive(Default)]
+pub struct Namespace {
+    names: HashSet<String>,
+    reserved: HashSet<String>,
+}
+
+impl Namespace {
+    /// Identifiers that collide with reserved names get prefixed with their kind
+    pub fn new(reserved: &[&str]) -> Self {
+        Self {
+            names: HashSet::new(),
+            reserved: reserved.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.contains(name) || self.reserved.contains(name)
+    }
+
+    /// Convert an LDtk identifier with the naming policy, sanitize it and make it unique
+    /// in this namespace. Every rename other than the case conversion is reported.
+    /// `what` describes the identifier in the report
+    pub fn insert(
+        &mut self,
+        preferences: &Preferences,
+        identifier: &str,
+        case: Case,
+        what: &str,
+    ) -> String {
+        let converted = preferences.to_case(identifier, case);
+        self.insert_converted(identifier, converted, case, what)
+    }
+
+    /// Same as insert, but for names that are already in the right case
+    pub fn insert_name(&mut self, name: String, case: Case, what: &str) -> String {
+        let identifier = name.clone();
+        self.insert_converted(&identifier, name, case, what)
+    }
+
+    fn insert_converted(
+        &mut self,
+        identifier: &str,
+        converted: String,
+        case: Case,
+        what: &str,
+    ) -> String {
+        use convert_case::Casing;
+        let separator = if matches!(case, Case::Pascal) {
+            ""
+        } else {
+            "_"
+        };
+        let mut name = sanitize_identifier(&converted);
+        if self.reserved.contains(&name) {
+            let kind = what.split(' ').next().unwrap_or(what);
+            name = format!("{}{}{}", kind.to_case(case), separator, name);
+        }
+        if self.contains(&name) {
+            let base = name.trim_start_matches("r#").to_owned();
+            name = (2..)
+                .map(|index| format!("{base}{separator}{index}"))
+                .find(|name| !self.contains(name))
+                .unwrap();
+        }
+        if name != converted {
+            eprintln!("Note: renamed {what} {identifier:?} to {name}");
+        }
+        self.names.insert(name.clone());
+        name
+    }
+}
+
+/// Naive english plural of a snake case name
+pub fn plural(name: &str) -> String {
+    let mut chars = name.chars().rev();
+    let consonant_y = chars.next() == Some('y')
+        && chars
+            .next()
+            .is_some_and(|char| char.is_alphabetic() && !"aeiou".contains(char));
+    if consonant_y {
+        format!("{}ies", &name[..name.len() - 1])
+    } else if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+    {
+        format!("{name}es")
+    } else {
+        format!("{name}s")
+    }
+}
+
+pub struct RsLayerNames {
+    /// Name of the generated layer struct
+    pub type_name: String,
+    /// Name of the layer inside of Level
+    pub member: String,
+}
+
+/// Names that are referenced before the items they name are generated
+#[derive(Default)]
+pub struct RsNames {
+    /// Types of the generated module
+    pub types: Namespace,
+    /// Keyed by LDtk layer identifier
+    pub layers: HashMap<String, RsLayerNames>,
+    /// Level members of World, keyed by LDtk level identifier
+    pub levels: HashMap<String, String>,
+    /// Tileset constants, keyed by tileset UID
+    pub tilesets: HashMap<i64, String>,
+}
+
+/// Methods of the layer traits, that inherent layer methods can't be named after
+pub const LAYER_METHODS: &[&str] = &[
+    "size",
+    "visible",
+    "opacity",
+    "instance_offset",
+    "total_offset",
+    "pixel_size",
+    "grid_size",
+    "grid_to_px",
+    "px_to_grid",
+    "apply_parallax",
+    "remove_parallax",
+    "parallax_factor",
+    "pixel_offset",
+    "tile_pivot",
+    "autotiles_at",
+    "entities",
+    "entities_mut",
+    "iter_of",
+    "iter_of_mut",
+    "spatial_index",
+    "entities_in_rect",
+    "entities_at_point",
+    "entities_in_circle",
+    "nearest_entity",
+    "collision_rects",
+    "collision_outlines",
+    "set",
+    "fill_rect",
+    "clear",
+    "resize",
+    "copy_region",
+    "paste_region",
+    "cells",
+    "cells_mut",
+    "replace_cells",
+    "cells_changed",
+    "neighbors4",
+    "neighbors8",
+    "neighbor_mask",
+    "raycast",
+    "line_of_sight",
+    "flood_fill",
+    "connected_regions",
+    "region_map",
+    "find_path",
+    "find_path_with",
+];
+
+/// Methods of the generated Level struct
+pub const LEVEL_METHODS: &[&str] = &[
+    "layers",
+    "layers_mut",
+    "validate",
+    "level_to_world",
+    "world_to_level",
+    "entities_in_rect",
+    "entities_at_point",
+    "entities_in_circle",
+    "nearest_entity",
+];
+
+/// Constants of the header and of generated tables
+pub const RESERVED_CONSTANTS: &[&str] = &["NEIGHBOR_OFFSETS", "BLOB_INDICES", "BLOB_MASKS"];
+
+/// Members of the generated Level struct, that layers can't be named after
+pub const LEVEL_MEMBERS: &[&str] = &[
+    "bg_color",
+    "pixel_size",
+    "world_depth",
+    "world_x",
+    "world_y",
+    "fields",
+];
+
+impl RsNames {
+    pub fn new(preferences: &Preferences, project: &LdtkJson) -> Self {
+        let mut names = Self {
+            types: Namespace::new(RESERVED_TYPES),
+            ..Default::default()
+        };
+
+        let mut level_members = Namespace::new(LEVEL_MEMBERS);
+        for layer_json in &project.defs.layers {
+            let type_name =
+                names
+                    .types
+                    .insert(preferences, &layer_json.identifier, Case::Pascal, "layer");
+            let member = level_members.insert(
+                preferences,
+                &layer_json.identifier,
+                Case::Snake,
+                "layer member",
+            );
+            names.layers.insert(
+                layer_json.identifier.clone(),
+                RsLayerNames { type_name, member },
+            );
+        }
+
+        let mut world_members = Namespace::default();
+        for level in &project.levels {
+            let member = world_members.insert(preferences, &level.identifier, Case::Snake, "level");
+            names.levels.insert(level.identifier.clone(), member);
+        }
+
+        let mut constants = Namespace::new(RESERVED_CONSTANTS);
+        for tileset in &project.defs.tilesets {
+            let constant = constants.insert(
+                preferences,
+                &tileset.identifier,
+                Case::UpperSnake,
+                "tileset",
+            );
+            names.tilesets.insert(tileset.uid, constant);
+        }
+        names
+    }
+}
@@ -39,6 +39,125 @@ pub trait VectorImpl: Sized {
 define_vectors!();
 define_colors!();
 
+/* --- Collections --- */
+/// A vector that always contains at least one element
+#[derive([SERDE]Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[SERDE(
+    try_from = "Vec<T>",
+    into = "Vec<T>",
+    bound(serialize = "T: Clone + serde::Serialize", deserialize = "T: serde::Deserialize<'de>")
+)]
+pub struct NonEmpty<T> {
+    elements: Vec<T>,
+}
+
+impl<T> NonEmpty<T> {
+    pub fn new(first: T, rest: Vec<T>) -> Self {
+        let mut elements = Vec::with_capacity(rest.len() + 1);
+        elements.push(first);
+        elements.extend(rest);
+        Self { elements }
+    }
+
+    /// Returns None if the vector is empty
+    pub fn from_vec(elements: Vec<T>) -> Option<Self> {
+        if elements.is_empty() {
+            None
+        } else {
+            Some(Self { elements })
+        }
+    }
+
+    pub fn first(&self) -> &T {
+        &self.elements[0]
+    }
+
+    pub fn first_mut(&mut self) -> &mut T {
+        &mut self.elements[0]
+    }
+
+    pub fn last(&self) -> &T {
+        &self.elements[self.elements.len() - 1]
+    }
+
+    pub fn last_mut(&mut self) -> &mut T {
+        let last = self.elements.len() - 1;
+        &mut self.elements[last]
+    }
+
+    pub fn push(&mut self, element: T) {
+        self.elements.push(element);
+    }
+
+    /// Removes the last element, unless it's the only one left
+    pub fn pop(&mut self) -> Option<T> {
+        if self.elements.len() > 1 {
+            self.elements.pop()
+        } else {
+            None
+        }
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.elements
+    }
+}
+
+impl<T> std::ops::Deref for NonEmpty<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.elements
+    }
+}
+
+impl<T> std::ops::DerefMut for NonEmpty<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.elements
+    }
+}
+
+impl<T> TryFrom<Vec<T>> for NonEmpty<T> {
+    type Error = &'static str;
+
+    fn try_from(elements: Vec<T>) -> Result<Self, Self::Error> {
+        Self::from_vec(elements).ok_or("NonEmpty needs at least one element")
+    }
+}
+
+impl<T> From<NonEmpty<T>> for Vec<T> {
+    fn from(value: NonEmpty<T>) -> Self {
+        value.elements
+    }
+}
+
+impl<T> IntoIterator for NonEmpty<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmpty<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut NonEmpty<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.iter_mut()
+    }
+}
+
 /* --- Tileset --- */
 pub type TilesetID = u32;
 
@@ -56,13 +175,190 @@ impl Tileset {
 
 #[derive([SERDE]Clone, Debug)]
 pub struct Tile {
+    /// Position inside of the tileset, in tiles
     pub position: UVec2,
     pub flip: FlipMode,
+    /// Offset from the top-left corner of its cell, in pixels
+    pub offset: IVec2,
+    /// Opacity (0 to 1)
+    pub alpha: f32,
 }
 
 impl Tile {
-    pub fn new(position: UVec2, flip: FlipMode) -> Self {
-        Self { position, flip }
+    pub fn new(position: UVec2, flip: FlipMode, offset: IVec2, alpha: f32) -> Self {
+        Self {
+            position,
+            flip,
+            offset,
+            alpha,
+        }
+    }
+
+    /// Exact position inside of the layer, in pixels
+    pub fn pixel_position(&self, cell: IVec2, grid_size: UVec2) -> IVec2 {
+        <IVec2 as VectorImpl>::new(
+            <IVec2 as VectorImpl>::x(&cell) * <UVec2 as VectorImpl>::x(&grid_size) as <IVec2 as VectorImpl>::T
+                + <IVec2 as VectorImpl>::x(&self.offset),
+            <IVec2 as VectorImpl>::y(&cell) * <UVec2 as VectorImpl>::y(&grid_size) as <IVec2 as VectorImpl>::T
+                + <IVec2 as VectorImpl>::y(&self.offset),
+        )
+    }
+}
+
+/// A tile placed by an auto layer rule
+#[derive([SERDE]Clone, Debug)]
+pub struct AutoTile {
+    pub tile: Tile,
+    /// UID of the rule that placed this tile
+    pub rule: u32,
+    /// UID of the group of the rule, see the rule group constants of the layer
+    pub group: u32,
+}
+
+impl AutoTile {
+    pub fn new(tile: Tile, rule: u32, group: u32) -> Self {
+        Self { tile, rule, group }
+    }
+}
+
+impl std::ops::Deref for AutoTile {
+    type Target = Tile;
+
+    fn deref(&self) -> &Self::Target {
+        &self.tile
+    }
+}
+
+/// Tiles of a cell of any layer from bottom to top, borrowed from the layer
+#[derive(Clone, Debug)]
+pub enum CellTiles<'a> {
+    Tiles(std::slice::Iter<'a, Tile>),
+    AutoTiles(std::slice::Iter<'a, AutoTile>),
+}
+
+impl Default for CellTiles<'_> {
+    fn default() -> Self {
+        Self::Tiles([].iter())
+    }
+}
+
+impl<'a> Iterator for CellTiles<'a> {
+    type Item = &'a Tile;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Tiles(tiles) => tiles.next(),
+            Self::AutoTiles(tiles) => tiles.next().map(|tile| &tile.tile),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Tiles(tiles) => tiles.size_hint(),
+            Self::AutoTiles(tiles) => tiles.size_hint(),
+        }
+    }
+}
+
+impl ExactSizeIterator for CellTiles<'_> {}
+
+/// The tiles of a cell, from bottom to top. A single tile is stored without allocating
+#[derive([SERDE]Clone, Debug, Default)]
+pub enum TileStack {
+    #[default]
+    Empty,
+    Single(Tile),
+    Stacked(Vec<Tile>),
+}
+
+impl TileStack {
+    pub fn as_slice(&self) -> &[Tile] {
+        match self {
+            Self::Empty => &[],
+            Self::Single(tile) => std::slice::from_ref(tile),
+            Self::Stacked(tiles) => tiles,
+        }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [Tile] {
+        match self {
+            Self::Empty => &mut [],
+            Self::Single(tile) => std::slice::from_mut(tile),
+            Self::Stacked(tiles) => tiles,
+        }
+    }
+
+    /// The visible tile
+    pub fn top(&self) -> Option<&Tile> {
+        self.as_slice().last()
+    }
+
+    /// Adds a tile on top of the others
+    pub fn push(&mut self, tile: Tile) {
+        *self = match std::mem::take(self) {
+            Self::Empty => Self::Single(tile),
+            Self::Single(first) => Self::Stacked(vec![first, tile]),
+            Self::Stacked(mut tiles) => {
+                tiles.push(tile);
+                Self::Stacked(tiles)
+            }
+        };
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::Empty;
+    }
+}
+
+impl std::ops::Deref for TileStack {
+    type Target = [Tile];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl std::ops::DerefMut for TileStack {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl From<Tile> for TileStack {
+    fn from(tile: Tile) -> Self {
+        Self::Single(tile)
+    }
+}
+
+/// A rectangle of tiles picked from a tileset
+#[derive([SERDE]Clone, Debug)]
+pub struct TileRect {
+    pub tileset: TilesetID,
+    /// Top-left tile, in tiles
+    pub tile: UVec2,
+    /// Size in tiles
+    pub size: UVec2,
+    /// Top-left corner inside of the tileset, in pixels
+    pub pixel_position: UVec2,
+    /// Size in pixels
+    pub pixel_size: UVec2,
+}
+
+impl TileRect {
+    pub fn new(
+        tileset: TilesetID,
+        tile: UVec2,
+        size: UVec2,
+        pixel_position: UVec2,
+        pixel_size: UVec2,
+    ) -> Self {
+        Self {
+            tileset,
+            tile,
+            size,
+            pixel_position,
+            pixel_size,
+        }
     }
 }
 
@@ -84,6 +380,991 @@ impl FlipMode {
     }
 }
 
+/* --- Auto layer rules --- */
+/// A group of auto layer rules, as defined in LDtk
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AutoRuleGroup {
+    pub uid: u32,
+    pub active: bool,
+    /// Optional groups only apply to layers that enable them
+    pub optional: bool,
+    pub rules: &'static [AutoRule],
+}
+
+/// An auto layer rule, as defined in LDtk.
+/// Perlin filters, biome requirements and random tile offsets are not supported
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AutoRule {
+    pub uid: u32,
+    pub active: bool,
+    /// Pattern width & height
+    pub size: u32,
+    /// IntGrid values to match, row by row, centered on the cell.
+    /// 0 matches anything, a positive value requires it and a negative value forbids it
+    pub pattern: &'static [i32],
+    /// Value of the cells outside of the layer, the rule fails on them if None
+    pub out_of_bounds_value: Option<i32>,
+    /// Chances for this rule to be applied (0 to 1)
+    pub chance: f64,
+    /// Opacity of the tiles (0 to 1)
+    pub alpha: f32,
+    /// Prevent the following rules from being applied on a matching cell
+    pub break_on_match: bool,
+    pub flip_x: bool,
+    pub flip_y: bool,
+    pub checker: RuleChecker,
+    pub x_modulo: i32,
+    pub y_modulo: i32,
+    pub x_offset: i32,
+    pub y_offset: i32,
+    /// Possible tile groups, one of them is picked randomly for each matching cell
+    pub tiles: &'static [&'static [AutoRuleTile]],
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RuleChecker {
+    None,
+    Horizontal,
+    Vertical,
+}
+
+/// A tile placed by an auto layer rule
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AutoRuleTile {
+    /// Position inside of the tileset, in tiles
+    pub tile: (u32, u32),
+    /// Offset from the matching cell, in pixels
+    pub offset: (i32, i32),
+    /// Tiles below an opaque tile of the same cell are not kept
+    pub opaque: bool,
+}
+
+impl AutoRule {
+    /// Pattern value matching any non-empty cell
+    pub const ANYTHING: i32 = 1000001;
+    /// Pattern values matching the cells of a group are multiples of this
+    pub const GROUP: i32 = 1000;
+
+    /// LDtk's coordinate hash, reproducing the float arithmetic of the editor
+    fn random(seed: f64, x: i32, y: i32, max: u32) -> u32 {
+        let to_i32 = |value: f64| value as i64 as i32;
+        let hash = seed + x as f64 * 374761393.0 + y as f64 * 668265263.0;
+        let hash = to_i32(hash) ^ (to_i32(hash) >> 13);
+        let hash = hash as f64 * 1274126177.0;
+        (to_i32(hash) ^ (to_i32(hash) >> 16)).unsigned_abs() % max
+    }
+
+    /// Checks the modulos and the checker mode
+    fn applies_at(&self, x: i32, y: i32) -> bool {
+        let vertical = match self.checker {
+            RuleChecker::Vertical => y + (x / self.x_modulo) % 2 - self.y_offset,
+            _ => y - self.y_offset,
+        };
+        let horizontal = match self.checker {
+            RuleChecker::Horizontal => x + (y / self.y_modulo) % 2 - self.x_offset,
+            _ => x - self.x_offset,
+        };
+        vertical % self.y_modulo == 0 && horizontal % self.x_modulo == 0
+    }
+
+    /// `value` returns the IntGrid value of a cell, or None outside of the layer.
+    /// `group` returns the group UID of an IntGrid value
+    fn matches(
+        &self,
+        seed: u32,
+        x: i32,
+        y: i32,
+        flip: FlipMode,
+        value: &impl Fn(i32, i32) -> Option<i32>,
+        group: &impl Fn(i32) -> Option<u32>,
+    ) -> bool {
+        if self.tiles.is_empty()
+            || self.chance <= 0.0
+            || self.chance < 1.0
+                && Self::random(seed as f64 + self.uid as f64, x, y, 100) as f64
+                    >= self.chance * 100.0
+        {
+            return false;
+        }
+        let size = self.size as i32;
+        let radius = size / 2;
+        let direction_x = if flip.horizontal() { -1 } else { 1 };
+        let direction_y = if flip.vertical() { -1 } else { 1 };
+        for (index, &expected) in self.pattern.iter().enumerate() {
+            if expected == 0 {
+                continue;
+            }
+            let index = index as i32;
+            let cell_value = match value(
+                x + direction_x * (index % size - radius),
+                y + direction_y * (index / size - radius),
+            )
+            .or(self.out_of_bounds_value)
+            {
+                Some(cell_value) => cell_value,
+                None => return false,
+            };
+            let matches = if expected.abs() == Self::ANYTHING {
+                cell_value != 0
+            } else if expected.abs() >= Self::GROUP {
+                group(cell_value) == Some((expected.abs() / Self::GROUP - 1) as u32)
+            } else {
+                cell_value == expected.abs()
+            };
+            if matches != (expected > 0) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Applies auto layer rules the way LDtk does, with the seed and optional rules of the layer,
+/// on the cells of a rectangle. Returns the auto tiles of each cell of the rectangle, row by row.
+/// Tiles placed outside of the layer go on the closest cell
+pub fn solve_auto_rules<L: traits::AutoLayer>(
+    layer: &L,
+    groups: &[AutoRuleGroup],
+    (start_x, start_y, width, height): (i32, i32, i32, i32),
+    value: impl Fn(i32, i32) -> Option<i32>,
+    value_group: impl Fn(i32) -> Option<u32>,
+) -> Vec<Vec<AutoTile>> {
+    let seed = layer.seed();
+    let optional_rules = layer.optional_rules();
+    let grid_size = L::GRID_SIZE as i32;
+    let layer_size = layer.size();
+    let (layer_width, layer_height) = (
+        <UVec2 as VectorImpl>::x(&layer_size) as i32,
+        <UVec2 as VectorImpl>::y(&layer_size) as i32,
+    );
+    let rules = groups
+        .iter()
+        .filter(|group| group.active && (!group.optional || optional_rules.contains(&group.uid)))
+        .flat_map(|group| group.rules.iter().map(|rule| (group.uid, rule)))
+        .filter(|(_, rule)| rule.active);
+    // Stamps can place tiles on other cells
+    let reach = rules
+        .clone()
+        .flat_map(|(_, rule)| rule.tiles.iter().copied().flatten())
+        .map(|tile| (tile.offset.0.abs().max(tile.offset.1.abs()) + grid_size - 1) / grid_size)
+        .max()
+        .unwrap_or(0);
+    let origins = (
+        start_x - reach,
+        start_y - reach,
+        width + reach * 2,
+        height + reach * 2,
+    );
+
+    let mut cells: Vec<Vec<(usize, AutoTile)>> = vec![Vec::new(); (width * height).max(0) as usize];
+    let mut covered = vec![false; cells.len()];
+    let mut broken = vec![false; (origins.2 * origins.3).max(0) as usize];
+    for (rule_index, (group_uid, rule)) in rules.enumerate() {
+        for (origin_index, broken) in broken.iter_mut().enumerate() {
+            let x = origins.0 + origin_index as i32 % origins.2;
+            let y = origins.1 + origin_index as i32 / origins.2;
+            if *broken || value(x, y).is_none() || !rule.applies_at(x, y) {
+                continue;
+            }
+            let mut matched = false;
+            for flip in [
+                FlipMode::None,
+                FlipMode::Horizontal,
+                FlipMode::Vertical,
+                FlipMode::Both,
+            ] {
+                if matched && rule.break_on_match
+                    || flip.horizontal() && !rule.flip_x
+                    || flip.vertical() && !rule.flip_y
+                    || !rule.matches(seed, x, y, flip, &value, &value_group)
+                {
+                    continue;
+                }
+                matched = true;
+                let tiles = rule.tiles[AutoRule::random(
+                    seed as f64 + rule.uid as f64,
+                    x,
+                    y,
+                    rule.tiles.len() as u32,
+                ) as usize];
+                for tile in tiles {
+                    let offset_x = if flip.horizontal() { -tile.offset.0 } else { tile.offset.0 };
+                    let offset_y = if flip.vertical() { -tile.offset.1 } else { tile.offset.1 };
+                    let pixel_x = x * grid_size + offset_x;
+                    let pixel_y = y * grid_size + offset_y;
+                    // Tiles pushed out of the layer go on the closest cell, keeping their offset
+                    let layer_x = pixel_x.div_euclid(grid_size).min(layer_width - 1).max(0);
+                    let layer_y = pixel_y.div_euclid(grid_size).min(layer_height - 1).max(0);
+                    let (cell_x, cell_y) = (layer_x - start_x, layer_y - start_y);
+                    if cell_x < 0 || cell_y < 0 || cell_x >= width || cell_y >= height {
+                        continue;
+                    }
+                    let cell = (cell_x + cell_y * width) as usize;
+                    if covered[cell] {
+                        continue;
+                    }
+                    cells[cell].push((
+                        rule_index,
+                        AutoTile::new(
+                            Tile::new(
+                                <UVec2 as VectorImpl>::new(tile.tile.0 as _, tile.tile.1 as _),
+                                flip,
+                                <IVec2 as VectorImpl>::new(
+                                    (pixel_x - layer_x * grid_size) as _,
+                                    (pixel_y - layer_y * grid_size) as _,
+                                ),
+                                rule.alpha,
+                            ),
+                            rule.uid,
+                            group_uid,
+                        ),
+                    ));
+                    if tile.opaque && tile.offset == (0, 0) {
+                        covered[cell] = true;
+                    }
+                }
+            }
+            if matched && rule.break_on_match {
+                *broken = true;
+            }
+        }
+    }
+
+    // Rules are drawn from the last to the first
+    cells
+        .into_iter()
+        .map(|mut tiles| {
+            tiles.sort_by_key(|(rule_index, _)| std::cmp::Reverse(*rule_index));
+            tiles.into_iter().map(|(_, tile)| tile).collect()
+        })
+        .collect()
+}
+
+/// New auto tiles of every cell the rules can change after the cells of a rectangle have changed
+fn recompute_autotiles<L: traits::AutoLayer>(
+    layer: &L,
+    groups: &[AutoRuleGroup],
+    start: IVec2,
+    size: UVec2,
+    value: impl Fn(i32, i32) -> Option<i32>,
+    value_group: impl Fn(i32) -> Option<u32>,
+) -> Vec<(IVec2, Vec<AutoTile>)> {
+    let radius = groups
+        .iter()
+        .flat_map(|group| group.rules)
+        .map(|rule| rule.size as i32 / 2)
+        .max()
+        .unwrap_or(0);
+    let rectangle = (
+        <IVec2 as VectorImpl>::x(&start) as i32 - radius,
+        <IVec2 as VectorImpl>::y(&start) as i32 - radius,
+        <UVec2 as VectorImpl>::x(&size) as i32 + radius * 2,
+        <UVec2 as VectorImpl>::y(&size) as i32 + radius * 2,
+    );
+    solve_auto_rules(layer, groups, rectangle, value, value_group)
+        .into_iter()
+        .enumerate()
+        .map(|(index, tiles)| {
+            let x = rectangle.0 + index as i32 % rectangle.2;
+            let y = rectangle.1 + index as i32 / rectangle.2;
+            (<IVec2 as VectorImpl>::new(x as _, y as _), tiles)
+        })
+        .collect()
+}
+
+/* --- IntGrid geometry --- */
+/// A rectangle of cells, in grid and pixel coordinates
+#[derive([SERDE]Clone, Copy, Debug)]
+pub struct CellRect {
+    /// Top-left cell
+    pub position: IVec2,
+    /// Size in cells
+    pub size: UVec2,
+    /// Top-left corner, in pixels, placed at the offset of the layer
+    pub pixel_position: IVec2,
+    /// Size in pixels
+    pub pixel_size: UVec2,
+}
+
+impl CellRect {
+    pub fn new(
+        position: (i32, i32),
+        size: (u32, u32),
+        grid_size: u32,
+        offset: (i32, i32),
+    ) -> Self {
+        Self {
+            position: <IVec2 as VectorImpl>::new(position.0 as _, position.1 as _),
+            size: <UVec2 as VectorImpl>::new(size.0 as _, size.1 as _),
+            pixel_position: <IVec2 as VectorImpl>::new(
+                (offset.0 + position.0 * grid_size as i32) as _,
+                (offset.1 + position.1 * grid_size as i32) as _,
+            ),
+            pixel_size: <UVec2 as VectorImpl>::new(
+                (size.0 * grid_size) as _,
+                (size.1 * grid_size) as _,
+            ),
+        }
+    }
+}
+
+/// Greedily merges solid cells into rectangles, growing each one right then down
+pub fn merge_cells(
+    (width, height): (i32, i32),
+    solid: impl Fn(i32, i32) -> bool,
+) -> Vec<((i32, i32), (u32, u32))> {
+    let mut taken = vec![false; (width.max(0) * height.max(0)) as usize];
+    let free = |taken: &[bool], x: i32, y: i32| !taken[(y * width + x) as usize] && solid(x, y);
+    let mut rects = Vec::new();
+    for y in 0..height {
+        for x in 0..width {
+            if !free(&taken, x, y) {
+                continue;
+            }
+            let mut right = x + 1;
+            while right < width && free(&taken, right, y) {
+                right += 1;
+            }
+            let mut bottom = y + 1;
+            while bottom < height && (x..right).all(|x| free(&taken, x, bottom)) {
+                bottom += 1;
+            }
+            for y in y..bottom {
+                for x in x..right {
+                    taken[(y * width + x) as usize] = true;
+                }
+            }
+            rects.push(((x, y), ((right - x) as u32, (bottom - y) as u32)));
+        }
+    }
+    rects
+}
+
+/// Traces the borders of solid cells into closed loops of cell corners, without
+/// collinear points. Outer borders go clockwise (with y pointing down) and holes
+/// counter-clockwise, so solid cells are always on the right. Cells touching by a corner
+/// only get separate loops
+pub fn trace_outlines(
+    (width, height): (i32, i32),
+    solid: impl Fn(i32, i32) -> bool,
+) -> Vec<Vec<(i32, i32)>> {
+    let solid = |x: i32, y: i32| x >= 0 && y >= 0 && x < width && y < height && solid(x, y);
+    // Outgoing border edges of each corner, as directions
+    let mut edges = std::collections::BTreeMap::<(i32, i32), Vec<(i32, i32)>>::new();
+    for y in 0..height {
+        for x in 0..width {
+            if !solid(x, y) {
+                continue;
+            }
+            for (neighbour, corner, direction) in [
+                ((0, -1), (x, y), (1, 0)),
+                ((1, 0), (x + 1, y), (0, 1)),
+                ((0, 1), (x + 1, y + 1), (-1, 0)),
+                ((-1, 0), (x, y + 1), (0, -1)),
+            ] {
+                if !solid(x + neighbour.0, y + neighbour.1) {
+                    edges.entry(corner).or_default().push(direction);
+                }
+            }
+        }
+    }
+
+    let mut outlines = Vec::new();
+    while let Some(mut entry) = edges.first_entry() {
+        let start = *entry.key();
+        let first = entry.get_mut().pop().unwrap();
+        if entry.get().is_empty() {
+            entry.remove();
+        }
+
+        let mut outline = vec![start];
+        let (mut corner, mut direction) = (start, first);
+        loop {
+            corner = (corner.0 + direction.0, corner.1 + direction.1);
+            let outgoing = edges.get(&corner).map(Vec::as_slice).unwrap_or_default();
+            // Turning right first keeps loops apart where cells touch by a corner
+            let next = [
+                (-direction.1, direction.0),
+                direction,
+                (direction.1, -direction.0),
+            ]
+            .into_iter()
+            .find(|turn| outgoing.contains(turn) || (corner == start && *turn == first))
+            .expect("Borders of cells always form loops");
+            if corner == start && next == first {
+                if direction == first {
+                    outline.remove(0);
+                }
+                break;
+            }
+
+            let outgoing = edges.get_mut(&corner).unwrap();
+            outgoing.retain(|edge| *edge != next);
+            if outgoing.is_empty() {
+                edges.remove(&corner);
+            }
+            if next != direction {
+                outline.push(corner);
+            }
+            direction = next;
+        }
+        outlines.push(outline);
+    }
+    outlines
+}
+
+/* --- Editing --- */
+/// A cell outside of a layer was edited
+#[derive([SERDE]Clone, Copy, Debug)]
+pub struct OutsideLayer {
+    pub position: IVec2,
+}
+
+impl std::fmt::Display for OutsideLayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Cell ({}, {}) is outside of the layer",
+            <IVec2 as VectorImpl>::x(&self.position),
+            <IVec2 as VectorImpl>::y(&self.position)
+        )
+    }
+}
+
+impl std::error::Error for OutsideLayer {}
+
+/// Cells copied out of a layer, to paste them into layers of the same type
+#[derive([SERDE]Clone, Debug)]
+pub struct CellBlock<T> {
+    pub size: UVec2,
+    /// Cells row by row
+    pub cells: Vec<T>,
+}
+
+impl<T> CellBlock<T> {
+    pub fn get(&self, position: IVec2) -> Option<&T> {
+        self.cells.get(cell_index(self.size, position)?)
+    }
+}
+
+/// Index of a cell in cells stored row by row, None outside of them
+fn cell_index(size: UVec2, position: IVec2) -> Option<usize> {
+    let (x, y) = (
+        <IVec2 as VectorImpl>::x(&position) as i64,
+        <IVec2 as VectorImpl>::y(&position) as i64,
+    );
+    let width = <UVec2 as VectorImpl>::x(&size) as i64;
+    if x < 0 || y < 0 || x >= width || y >= <UVec2 as VectorImpl>::y(&size) as i64 {
+        return None;
+    }
+    Some((y * width + x) as usize)
+}
+
+/// Part of a rectangle inside cells of the given size, as start and end (excluded)
+fn clip_rect(size: UVec2, start: IVec2, rect_size: UVec2) -> ((i32, i32), (i32, i32)) {
+    let (x, y) = (
+        <IVec2 as VectorImpl>::x(&start) as i32,
+        <IVec2 as VectorImpl>::y(&start) as i32,
+    );
+    let end = |start: i32, length: i64, limit: i64| (start as i64 + length).min(limit) as i32;
+    (
+        (x.max(0), y.max(0)),
+        (
+            end(
+                x,
+                <UVec2 as VectorImpl>::x(&rect_size) as i64,
+                <UVec2 as VectorImpl>::x(&size) as i64,
+            ),
+            end(
+                y,
+                <UVec2 as VectorImpl>::y(&rect_size) as i64,
+                <UVec2 as VectorImpl>::y(&size) as i64,
+            ),
+        ),
+    )
+}
+
+/// Start and size of a rectangle from clip_rect, None when it is empty
+fn clipped_rect((left, top): (i32, i32), (right, bottom): (i32, i32)) -> Option<(IVec2, UVec2)> {
+    (left < right && top < bottom).then(|| {
+        (
+            <IVec2 as VectorImpl>::new(left as _, top as _),
+            <UVec2 as VectorImpl>::new((right - left) as _, (bottom - top) as _),
+        )
+    })
+}
+
+/* --- Neighbours --- */
+/// Offsets of the neighbours of a cell, clockwise from above, in the order of the bits
+/// of neighbor masks
+const NEIGHBOR_OFFSETS: [(i32, i32); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// What cells outside of a layer count as in neighbor masks
+#[derive([SERDE]Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutOfBounds {
+    /// They never match
+    #[default]
+    Empty,
+    /// They always match
+    Matching,
+    /// They are the closest cell of the layer
+    Clamp,
+}
+
+/* --- Raycasting --- */
+/// Where a ray entered a cell
+#[derive([SERDE]Clone, Copy, Debug)]
+pub struct RayHit {
+    pub cell: IVec2,
+    /// Point on the border of the cell, in pixels
+    pub point: FVec2,
+    /// Side of the cell that was hit, zero when the ray started inside of the cell
+    pub normal: IVec2,
+    /// Distance from the origin of the ray, in pixels
+    pub distance: f32,
+}
+
+/// Walks the cells along a ray with DDA, returning the first solid one closer than
+/// max_distance. Cells are grid_size pixels large, and the grid starts at offset
+pub fn cast_ray(
+    (width, height): (i32, i32),
+    grid_size: u32,
+    offset: (f32, f32),
+    origin: (f32, f32),
+    direction: (f32, f32),
+    max_distance: f32,
+    solid: impl Fn(i32, i32) -> bool,
+) -> Option<RayHit> {
+    let grid_size = grid_size as f32;
+    let length = direction.0.hypot(direction.1);
+    let direction = if length > 0.0 {
+        (direction.0 / length, direction.1 / length)
+    } else {
+        (0.0, 0.0)
+    };
+    // Position in cells
+    let start = (
+        (origin.0 - offset.0) / grid_size,
+        (origin.1 - offset.1) / grid_size,
+    );
+    let mut cell = (start.0.floor() as i32, start.1.floor() as i32);
+    let sign = |value: f32| {
+        if value > 0.0 {
+            1
+        } else if value < 0.0 {
+            -1
+        } else {
+            0
+        }
+    };
+    let step = (sign(direction.0), sign(direction.1));
+    // Distance in cells to the next border on each axis, and between borders
+    let border = |start: f32, cell: i32, step: i32, direction: f32| match step {
+        0 => f32::INFINITY,
+        1 => (cell as f32 + 1.0 - start) / direction,
+        _ => (cell as f32 - start) / direction,
+    };
+    let mut next = (
+        border(start.0, cell.0, step.0, direction.0),
+        border(start.1, cell.1, step.1, direction.1),
+    );
+    let delta = (
+        (1.0 / direction.0).abs(),
+        (1.0 / direction.1).abs(),
+    );
+
+    let (mut distance, mut normal) = (0.0, (0, 0));
+    loop {
+        if distance * grid_size > max_distance {
+            return None;
+        }
+        if solid(cell.0, cell.1) {
+            return Some(RayHit {
+                cell: <IVec2 as VectorImpl>::new(cell.0 as _, cell.1 as _),
+                point: <FVec2 as VectorImpl>::new(
+                    (origin.0 + direction.0 * distance * grid_size) as _,
+                    (origin.1 + direction.1 * distance * grid_size) as _,
+                ),
+                normal: <IVec2 as VectorImpl>::new(normal.0 as _, normal.1 as _),
+                distance: distance * grid_size,
+            });
+        }
+        // Nothing is left to hit once the ray goes away from the grid
+        let leaving = |cell: i32, step: i32, size: i32| {
+            (cell < 0 && step <= 0) || (cell >= size && step >= 0)
+        };
+        if step == (0, 0) || leaving(cell.0, step.0, width) || leaving(cell.1, step.1, height) {
+            return None;
+        }
+        if next.0 < next.1 {
+            distance = next.0;
+            next.0 += delta.0;
+            cell.0 += step.0;
+            normal = (-step.0, 0);
+        } else {
+            distance = next.1;
+            next.1 += delta.1;
+            cell.1 += step.1;
+            normal = (0, -step.1);
+        }
+    }
+}
+
+/* --- Parallax --- */
+/// Center (in level pixels) and parallax factor of a layer
+fn parallax_of<L: traits::Layer + ?Sized>(layer: &L) -> ((f32, f32), (f32, f32)) {
+    let offset = layer.total_offset();
+    let size = layer.pixel_size();
+    let factor = L::parallax_factor();
+    (
+        (
+            <IVec2 as VectorImpl>::x(&offset) as f32 + <UVec2 as VectorImpl>::x(&size) as f32 / 2.0,
+            <IVec2 as VectorImpl>::y(&offset) as f32 + <UVec2 as VectorImpl>::y(&size) as f32 / 2.0,
+        ),
+        (
+            <FVec2 as VectorImpl>::x(&factor) as f32,
+            <FVec2 as VectorImpl>::y(&factor) as f32,
+        ),
+    )
+}
+
+/* --- Regions --- */
+/// Index of a region in RegionMap::regions
+#[derive([SERDE]Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RegionId(pub u32);
+
+/// Cells connected by their sides
+#[derive([SERDE]Clone, Debug)]
+pub struct Region {
+    pub id: RegionId,
+    pub cells: Vec<IVec2>,
+    /// Bounding box of the cells
+    pub bounds: CellRect,
+}
+
+/// Region of every cell of a layer
+#[derive([SERDE]Clone, Debug)]
+pub struct RegionMap {
+    size: (i32, i32),
+    labels: Vec<Option<RegionId>>,
+    pub regions: Vec<Region>,
+}
+
+impl RegionMap {
+    /// Groups the solid cells of a grid into regions, numbered from the top-left.
+    /// The pixel bounds of the regions are placed at offset
+    pub fn new(
+        (width, height): (i32, i32),
+        grid_size: u32,
+        offset: (i32, i32),
+        solid: impl Fn(i32, i32) -> bool,
+    ) -> Self {
+        let mut labels = vec![None; (width.max(0) * height.max(0)) as usize];
+        let mut regions = Vec::new();
+        let mut filled = vec![false; labels.len()];
+        for y in 0..height {
+            for x in 0..width {
+                if filled[(y * width + x) as usize] || !solid(x, y) {
+                    continue;
+                }
+                let id = RegionId(regions.len() as u32);
+                let cells = fill_cells((width, height), (x, y), &solid, &mut filled);
+                let (mut min, mut max) = ((x, y), (x, y));
+                for &(x, y) in &cells {
+                    labels[(y * width + x) as usize] = Some(id);
+                    min = (min.0.min(x), min.1.min(y));
+                    max = (max.0.max(x), max.1.max(y));
+                }
+                regions.push(Region {
+                    id,
+                    cells: cells
+                        .into_iter()
+                        .map(|(x, y)| <IVec2 as VectorImpl>::new(x as _, y as _))
+                        .collect(),
+                    bounds: CellRect::new(
+                        min,
+                        ((max.0 - min.0 + 1) as u32, (max.1 - min.1 + 1) as u32),
+                        grid_size,
+                        offset,
+                    ),
+                });
+            }
+        }
+        Self {
+            size: (width, height),
+            labels,
+            regions,
+        }
+    }
+
+    /// Region of a cell, None outside of the layer and for cells that are in none
+    pub fn get(&self, position: IVec2) -> Option<RegionId> {
+        let (x, y) = (
+            <IVec2 as VectorImpl>::x(&position) as i32,
+            <IVec2 as VectorImpl>::y(&position) as i32,
+        );
+        if x < 0 || y < 0 || x >= self.size.0 || y >= self.size.1 {
+            return None;
+        }
+        self.labels.get((y * self.size.0 + x) as usize).copied().flatten()
+    }
+
+    pub fn region_at(&self, position: IVec2) -> Option<&Region> {
+        self.regions.get(self.get(position)?.0 as usize)
+    }
+}
+
+/// Solid cells connected to start by their sides, marking them as filled
+fn fill_cells(
+    (width, height): (i32, i32),
+    start: (i32, i32),
+    solid: impl Fn(i32, i32) -> bool,
+    filled: &mut [bool],
+) -> Vec<(i32, i32)> {
+    let mut cells = Vec::new();
+    let mut stack = vec![start];
+    filled[(start.1 * width + start.0) as usize] = true;
+    while let Some((x, y)) = stack.pop() {
+        cells.push((x, y));
+        for (x, y) in [(x + 1, y), (x, y + 1), (x - 1, y), (x, y - 1)] {
+            if x < 0 || y < 0 || x >= width || y >= height {
+                continue;
+            }
+            let index = (y * width + x) as usize;
+            if !filled[index] && solid(x, y) {
+                filled[index] = true;
+                stack.push((x, y));
+            }
+        }
+    }
+    cells
+}
+
+/// Cells matching the predicate connected to start by their sides, including start.
+/// Empty when start doesn't match
+pub fn flood_fill(
+    (width, height): (i32, i32),
+    start: (i32, i32),
+    solid: impl Fn(i32, i32) -> bool,
+) -> Vec<(i32, i32)> {
+    if start.0 < 0 || start.1 < 0 || start.0 >= width || start.1 >= height || !solid(start.0, start.1)
+    {
+        return Vec::new();
+    }
+    fill_cells(
+        (width, height),
+        start,
+        solid,
+        &mut vec![false; (width * height) as usize],
+    )
+}
+
+/* --- Pathfinding --- */
+/// Cells a path can move to from a cell
+#[derive([SERDE]Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// Up, down, left and right
+    #[default]
+    Four,
+    /// Diagonals too, cost is multiplied by sqrt(2) for them
+    Eight(CornerCutting),
+}
+
+/// When a diagonal move may pass next to cells that can't be walked on
+#[derive([SERDE]Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CornerCutting {
+    /// Both cells on the sides of the move have to be walkable
+    #[default]
+    Never,
+    /// One of the cells on the sides of the move has to be walkable
+    OneSide,
+    /// Cells on the sides of the move don't matter
+    Always,
+}
+
+#[derive(Clone, Debug)]
+struct OpenCell {
+    estimate: f32,
+    cost: f32,
+    cell: u32,
+}
+
+impl PartialEq for OpenCell {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for OpenCell {}
+
+impl PartialOrd for OpenCell {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OpenCell {
+    /// Lowest estimate first, for BinaryHeap
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.estimate.total_cmp(&self.estimate)
+    }
+}
+
+/// State of A* searches, kept between them to not allocate on every search
+#[derive(Clone, Debug, Default)]
+pub struct PathBuffer {
+    size: (i32, i32),
+    /// Search each cell was last reached in, so cells don't have to be reset between searches
+    reached: Vec<u32>,
+    search: u32,
+    costs: Vec<f32>,
+    parents: Vec<u32>,
+    open: std::collections::BinaryHeap<OpenCell>,
+    path: Vec<IVec2>,
+}
+
+impl PathBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cheapest path from start to goal, including both, on a grid of the given size.
+    /// `cost` is the cost of entering a cell, None when it can't be walked on.
+    /// The path is the cheapest one as long as costs are at least 1
+    pub fn find_path(
+        &mut self,
+        (width, height): (i32, i32),
+        start: (i32, i32),
+        goal: (i32, i32),
+        neighbourhood: Neighbourhood,
+        cost: impl Fn(i32, i32) -> Option<f32>,
+    ) -> Option<&[IVec2]> {
+        let inside = |(x, y): (i32, i32)| x >= 0 && y >= 0 && x < width && y < height;
+        if !inside(start) || !inside(goal) || cost(goal.0, goal.1).is_none() {
+            return None;
+        }
+        if self.size != (width, height) {
+            self.size = (width, height);
+            let cells = (width * height) as usize;
+            self.reached = vec![0; cells];
+            self.costs = vec![0.0; cells];
+            self.parents = vec![0; cells];
+            self.search = 0;
+        }
+        self.search = self.search.wrapping_add(1);
+        if self.search == 0 {
+            self.reached.fill(0);
+            self.search = 1;
+        }
+        self.open.clear();
+        self.path.clear();
+
+        let diagonals = match neighbourhood {
+            Neighbourhood::Four => None,
+            Neighbourhood::Eight(corner_cutting) => Some(corner_cutting),
+        };
+        let heuristic = |(x, y): (i32, i32)| {
+            let (dx, dy) = ((x - goal.0).abs() as f32, (y - goal.1).abs() as f32);
+            match diagonals {
+                None => dx + dy,
+                Some(_) => dx + dy + (std::f32::consts::SQRT_2 - 2.0) * dx.min(dy),
+            }
+        };
+        let index = |(x, y): (i32, i32)| (y * width + x) as u32;
+        let walkable = |(x, y): (i32, i32)| inside((x, y)) && cost(x, y).is_some();
+
+        let start_index = index(start);
+        self.reached[start_index as usize] = self.search;
+        self.costs[start_index as usize] = 0.0;
+        self.parents[start_index as usize] = start_index;
+        self.open.push(OpenCell {
+            estimate: heuristic(start),
+            cost: 0.0,
+            cell: start_index,
+        });
+
+        while let Some(open) = self.open.pop() {
+            if open.cost > self.costs[open.cell as usize] {
+                continue;
+            }
+            let (x, y) = (open.cell as i32 % width, open.cell as i32 / width);
+            if (x, y) == goal {
+                let mut cell = open.cell;
+                loop {
+                    self.path.push(<IVec2 as VectorImpl>::new(
+                        (cell as i32 % width) as _,
+                        (cell as i32 / width) as _,
+                    ));
+                    if cell == start_index {
+                        break;
+                    }
+                    cell = self.parents[cell as usize];
+                }
+                self.path.reverse();
+                return Some(&self.path);
+            }
+
+            // Diagonals last, Four stops at them
+            for (dx, dy) in [
+                (1, 0),
+                (0, 1),
+                (-1, 0),
+                (0, -1),
+                (1, 1),
+                (-1, 1),
+                (-1, -1),
+                (1, -1),
+            ] {
+                let diagonal = dx != 0 && dy != 0;
+                if diagonal {
+                    let sides = (walkable((x + dx, y)), walkable((x, y + dy)));
+                    match diagonals {
+                        None => break,
+                        Some(CornerCutting::Never) if !(sides.0 && sides.1) => continue,
+                        Some(CornerCutting::OneSide) if !(sides.0 || sides.1) => continue,
+                        _ => {}
+                    }
+                }
+                let next = (x + dx, y + dy);
+                if !inside(next) {
+                    continue;
+                }
+                let Some(step) = cost(next.0, next.1) else {
+                    continue;
+                };
+                let step = if diagonal {
+                    step * std::f32::consts::SQRT_2
+                } else {
+                    step
+                };
+                let next_cost = open.cost + step;
+                let next_index = index(next) as usize;
+                if self.reached[next_index] == self.search && self.costs[next_index] <= next_cost {
+                    continue;
+                }
+                self.reached[next_index] = self.search;
+                self.costs[next_index] = next_cost;
+                self.parents[next_index] = open.cell;
+                self.open.push(OpenCell {
+                    estimate: next_cost + heuristic(next),
+                    cost: next_cost,
+                    cell: next_index as u32,
+                });
+            }
+        }
+        None
+    }
+}
+
 /* --- Entity --- */
 #[derive([SERDE]Clone, Debug)]
 pub struct EntityObject {
@@ -110,18 +1391,286 @@ impl EntityObject {
             ),
         )
     }
+
+    /// Left, top, right and bottom edges in pixels
+    fn bounds(&self) -> (f32, f32, f32, f32) {
+        let top_left = self.top_left();
+        let left = <FVec2 as VectorImpl>::x(&top_left) as f32;
+        let top = <FVec2 as VectorImpl>::y(&top_left) as f32;
+        (
+            left,
+            top,
+            left + <UVec2 as VectorImpl>::x(&self.size) as f32,
+            top + <UVec2 as VectorImpl>::y(&self.size) as f32,
+        )
+    }
+
+    /// Whether the point (in pixels) is inside this entity
+    pub fn contains(&self, point: FVec2) -> bool {
+        let (left, top, right, bottom) = self.bounds();
+        let (x, y) = (
+            <FVec2 as VectorImpl>::x(&point) as f32,
+            <FVec2 as VectorImpl>::y(&point) as f32,
+        );
+        left <= x && x < right && top <= y && y < bottom
+    }
+
+    /// Whether this entity overlaps the rectangle (in pixels)
+    pub fn overlaps(&self, start: FVec2, size: FVec2) -> bool {
+        let (left, top, right, bottom) = self.bounds();
+        let (x, y) = (
+            <FVec2 as VectorImpl>::x(&start) as f32,
+            <FVec2 as VectorImpl>::y(&start) as f32,
+        );
+        left < x + <FVec2 as VectorImpl>::x(&size) as f32
+            && x < right
+            && top < y + <FVec2 as VectorImpl>::y(&size) as f32
+            && y < bottom
+    }
+
+    /// Distance from the point (in pixels) to the closest point of this entity, 0 inside
+    pub fn distance_to(&self, point: FVec2) -> f32 {
+        let (left, top, right, bottom) = self.bounds();
+        let (x, y) = (
+            <FVec2 as VectorImpl>::x(&point) as f32,
+            <FVec2 as VectorImpl>::y(&point) as f32,
+        );
+        let dx = (left - x).max(x - right).max(0.0);
+        let dy = (top - y).max(y - bottom).max(0.0);
+        (dx * dx + dy * dy).sqrt()
+    }
+}
+
+/// Uniform grid over the entities of a layer, so that spatial queries only look at
+/// entities near the queried area
+#[derive(Clone, Debug, Default)]
+pub struct EntityGrid {
+    cell_size: f32,
+    start: (i32, i32),
+    size: (i32, i32),
+    /// Indices into the entities, for every cell, row by row
+    cells: Vec<Vec<usize>>,
+}
+
+impl EntityGrid {
+    pub fn new(entities: &[EntityObject], cell_size: u32) -> Self {
+        let cell_size = cell_size.max(1) as f32;
+        let cell_bounds = |entity: &EntityObject| {
+            let (left, top, right, bottom) = entity.bounds();
+            (
+                (left / cell_size).floor() as i32,
+                (top / cell_size).floor() as i32,
+                (right / cell_size).floor() as i32,
+                (bottom / cell_size).floor() as i32,
+            )
+        };
+        let Some((min_x, min_y, max_x, max_y)) = entities.iter().map(cell_bounds).reduce(
+            |(min_x, min_y, max_x, max_y), (left, top, right, bottom)| {
+                (
+                    min_x.min(left),
+                    min_y.min(top),
+                    max_x.max(right),
+                    max_y.max(bottom),
+                )
+            },
+        ) else {
+            return Self {
+                cell_size,
+                ..Default::default()
+            };
+        };
+
+        let size = (max_x - min_x + 1, max_y - min_y + 1);
+        let mut cells = vec![Vec::new(); (size.0 * size.1) as usize];
+        for (index, entity) in entities.iter().enumerate() {
+            let (left, top, right, bottom) = cell_bounds(entity);
+            for y in top..=bottom {
+                for x in left..=right {
+                    cells[((y - min_y) * size.0 + x - min_x) as usize].push(index);
+                }
+            }
+        }
+        Self {
+            cell_size,
+            start: (min_x, min_y),
+            size,
+            cells,
+        }
+    }
+
+    /// Sorted indices of the entities that may overlap the rectangle (in pixels)
+    pub fn candidates(&self, start: FVec2, size: FVec2) -> Vec<usize> {
+        let x = <FVec2 as VectorImpl>::x(&start) as f32;
+        let y = <FVec2 as VectorImpl>::y(&start) as f32;
+        let cell = |value: f32, start: i32, size: i32| {
+            ((value / self.cell_size).floor() as i32 - start).clamp(-1, size)
+        };
+        let (left, top) = (
+            cell(x, self.start.0, self.size.0).max(0),
+            cell(y, self.start.1, self.size.1).max(0),
+        );
+        let (right, bottom) = (
+            cell(x + <FVec2 as VectorImpl>::x(&size) as f32, self.start.0, self.size.0)
+                .min(self.size.0 - 1),
+            cell(y + <FVec2 as VectorImpl>::y(&size) as f32, self.start.1, self.size.1)
+                .min(self.size.1 - 1),
+        );
+
+        let mut candidates = Vec::new();
+        for y in top..=bottom {
+            for x in left..=right {
+                candidates.extend(&self.cells[(y * self.size.0 + x) as usize]);
+            }
+        }
+        candidates.sort_unstable();
+        candidates.dedup();
+        candidates
+    }
+
+    /// Whether the rectangle (in pixels) covers every cell of the grid
+    fn covers(&self, start: FVec2, size: FVec2) -> bool {
+        let x = <FVec2 as VectorImpl>::x(&start) as f32;
+        let y = <FVec2 as VectorImpl>::y(&start) as f32;
+        x <= self.start.0 as f32 * self.cell_size
+            && y <= self.start.1 as f32 * self.cell_size
+            && x + <FVec2 as VectorImpl>::x(&size) as f32
+                >= (self.start.0 + self.size.0) as f32 * self.cell_size
+            && y + <FVec2 as VectorImpl>::y(&size) as f32
+                >= (self.start.1 + self.size.1) as f32 * self.cell_size
+    }
+}
+
+/// Entities passing `filter`, looking only at the ones the index puts near the rectangle
+fn query_entities<'a>(
+    entities: &'a [EntityObject],
+    index: Option<&EntityGrid>,
+    start: FVec2,
+    size: FVec2,
+    filter: impl Fn(&EntityObject) -> bool,
+) -> Vec<&'a EntityObject> {
+    match index {
+        Some(index) => index
+            .candidates(start, size)
+            .into_iter()
+            .map(|index| &entities[index])
+            .filter(|entity| filter(entity))
+            .collect(),
+        None => entities.iter().filter(|entity| filter(entity)).collect(),
+    }
+}
+
+/// Entity closest to the point, searching squares of growing size when indexed
+fn nearest_entity<'a>(
+    entities: &'a [EntityObject],
+    index: Option<&EntityGrid>,
+    point: FVec2,
+) -> Option<&'a EntityObject> {
+    let closest = |entities: &mut dyn Iterator<Item = &'a EntityObject>| {
+        entities.min_by(|a, b| a.distance_to(point).total_cmp(&b.distance_to(point)))
+    };
+    let Some(index) = index else {
+        return closest(&mut entities.iter());
+    };
+    if entities.is_empty() {
+        return None;
+    }
+
+    let mut radius = index.cell_size;
+    loop {
+        let start = <FVec2 as VectorImpl>::new(
+            (<FVec2 as VectorImpl>::x(&point) as f32 - radius) as _,
+            (<FVec2 as VectorImpl>::y(&point) as f32 - radius) as _,
+        );
+        let size = <FVec2 as VectorImpl>::new((radius * 2.0) as _, (radius * 2.0) as _);
+        // Entities further than the radius may be beaten by ones outside the square
+        let everything = index.covers(start, size) || radius.is_infinite();
+        let nearest = closest(
+            &mut index
+                .candidates(start, size)
+                .into_iter()
+                .map(|index| &entities[index])
+                .filter(|entity| everything || entity.distance_to(point) <= radius),
+        );
+        if nearest.is_some() || everything {
+            return nearest;
+        }
+        radius *= 2.0;
+    }
+}
+
+/// An entity of a known type, with the placement of its EntityObject
+#[derive(Clone, Copy, Debug)]
+pub struct TypedEntity<'a, T> {
+    pub entity: &'a T,
+    pub position: FVec2,
+    pub size: UVec2,
+}
+
+impl<'a, T: traits::EntityType> TypedEntity<'a, T> {
+    pub fn from_object(object: &'a EntityObject) -> Option<Self> {
+        Some(Self {
+            entity: T::from_entity(&object.entity)?,
+            position: object.position,
+            size: object.size,
+        })
+    }
+}
+
+/// A mutable entity of a known type, with the placement of its EntityObject
+#[derive(Debug)]
+pub struct TypedEntityMut<'a, T> {
+    pub entity: &'a mut T,
+    pub position: &'a mut FVec2,
+    pub size: &'a mut UVec2,
+}
+
+impl<'a, T: traits::EntityType> TypedEntityMut<'a, T> {
+    pub fn from_object(object: &'a mut EntityObject) -> Option<Self> {
+        Some(Self {
+            entity: T::from_entity_mut(&mut object.entity)?,
+            position: &mut object.position,
+            size: &mut object.size,
+        })
+    }
+}
+
+impl<T> std::ops::Deref for TypedEntity<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.entity
+    }
+}
+
+impl<T> std::ops::Deref for TypedEntityMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.entity
+    }
+}
+
+impl<T> std::ops::DerefMut for TypedEntityMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.entity
+    }
 }
 
+pub type EntitiesOf<'a, T> = std::iter::FilterMap<
+    std::slice::Iter<'a, EntityObject>,
+    fn(&'a EntityObject) -> Option<TypedEntity<'a, T>>,
+>;
+pub type EntitiesOfMut<'a, T> = std::iter::FilterMap<
+    std::slice::IterMut<'a, EntityObject>,
+    fn(&'a mut EntityObject) -> Option<TypedEntityMut<'a, T>>,
+>;
+
 #[derive([SERDE]Clone, Debug)]
 pub enum RenderMode {
     Rectangle,
     Ellipse,
     Cross,
-    Tile {
-        tileset: TilesetID,
-        tile: UVec2,
-        size: UVec2,
-    },
+    Tile(TileRect),
 }
 
 #[derive([SERDE]Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -157,6 +1706,27 @@ impl EntityRef {
     }
 }
 
+/* --- Validation --- */
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ValidationError {
+    pub field: &'static str,
+    pub message: &'static str,
+}
+
+impl ValidationError {
+    pub const fn new(field: &'static str, message: &'static str) -> Self {
+        Self { field, message }
+    }
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Field {} {}", self.field, self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
 /* --- World --- */
 #[derive([SERDE]Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum WorldLayout {
@@ -181,6 +1751,17 @@ pub mod traits {
         fn tile_pivot() -> FVec2;
 
         fn size(&self) -> UVec2;
+        fn visible(&self) -> bool;
+        /// Opacity of this instance (0 to 1)
+        fn opacity(&self) -> f32;
+        /// Pixel offset of this instance, added to the offset of the definition
+        fn instance_offset(&self) -> IVec2;
+
+        /// Pixel offset to render this layer at
+        fn total_offset(&self) -> IVec2 {
+            <IVec2 as VectorImpl>::add(Self::pixel_offset(), self.instance_offset())
+        }
+
         fn pixel_size(&self) -> UVec2 {
             <UVec2 as VectorImpl>::mul(self.size(), self.grid_size())
         }
@@ -188,11 +1769,89 @@ pub mod traits {
         fn grid_size(&self) -> UVec2 {
             <UVec2 as VectorImpl>::new(Self::GRID_SIZE as _, Self::GRID_SIZE as _)
         }
+
+        /// Top left corner of a cell, in level pixels
+        fn grid_to_px(&self, cell: IVec2) -> IVec2 {
+            let offset = self.total_offset();
+            <IVec2 as VectorImpl>::new(
+                (<IVec2 as VectorImpl>::x(&cell) as i32 * Self::GRID_SIZE as i32
+                    + <IVec2 as VectorImpl>::x(&offset) as i32) as _,
+                (<IVec2 as VectorImpl>::y(&cell) as i32 * Self::GRID_SIZE as i32
+                    + <IVec2 as VectorImpl>::y(&offset) as i32) as _,
+            )
+        }
+
+        /// Cell containing a point in level pixels, which may be outside of the layer
+        fn px_to_grid(&self, position: FVec2) -> IVec2 {
+            let offset = self.total_offset();
+            let cell = |position: f32, offset: f32| {
+                ((position - offset) / Self::GRID_SIZE as f32).floor() as i32
+            };
+            <IVec2 as VectorImpl>::new(
+                cell(
+                    <FVec2 as VectorImpl>::x(&position) as f32,
+                    <IVec2 as VectorImpl>::x(&offset) as f32,
+                ) as _,
+                cell(
+                    <FVec2 as VectorImpl>::y(&position) as f32,
+                    <IVec2 as VectorImpl>::y(&offset) as f32,
+                ) as _,
+            )
+        }
+
+        /// Where a point of this layer (in level pixels) is drawn for a camera centered on `camera`
+        /// (in level pixels): the layer moves by its parallax factor times the distance from
+        /// the camera to its center, and shrinks by the factor with PARALLAX_SCALING
+        fn apply_parallax(&self, position: FVec2, camera: FVec2) -> FVec2 {
+            let (center, factor) = super::parallax_of(self);
+            let apply = |position: f32, camera: f32, center: f32, factor: f32| {
+                let scale = if Self::PARALLAX_SCALING { 1.0 - factor } else { 1.0 };
+                center + (camera - center) * factor + (position - center) * scale
+            };
+            <FVec2 as VectorImpl>::new(
+                apply(
+                    <FVec2 as VectorImpl>::x(&position) as f32,
+                    <FVec2 as VectorImpl>::x(&camera) as f32,
+                    center.0,
+                    factor.0,
+                ) as _,
+                apply(
+                    <FVec2 as VectorImpl>::y(&position) as f32,
+                    <FVec2 as VectorImpl>::y(&camera) as f32,
+                    center.1,
+                    factor.1,
+                ) as _,
+            )
+        }
+
+        /// Point of this layer (in level pixels) drawn at a position, for a camera centered on `camera`.
+        /// Undoes apply_parallax, except for a scaled layer with a factor of 1 that is drawn as a point
+        fn remove_parallax(&self, position: FVec2, camera: FVec2) -> FVec2 {
+            let (center, factor) = super::parallax_of(self);
+            let remove = |position: f32, camera: f32, center: f32, factor: f32| {
+                let scale = if Self::PARALLAX_SCALING { 1.0 - factor } else { 1.0 };
+                center + (position - center - (camera - center) * factor) / scale
+            };
+            <FVec2 as VectorImpl>::new(
+                remove(
+                    <FVec2 as VectorImpl>::x(&position) as f32,
+                    <FVec2 as VectorImpl>::x(&camera) as f32,
+                    center.0,
+                    factor.0,
+                ) as _,
+                remove(
+                    <FVec2 as VectorImpl>::y(&position) as f32,
+                    <FVec2 as VectorImpl>::y(&camera) as f32,
+                    center.1,
+                    factor.1,
+                ) as _,
+            )
+        }
     }
 
     /// A trait for layers that can be indexed
     pub trait IndexableLayer: Layer {
-        type Tile;
+        type Tile: ?Sized;
 
         fn get(&self, position: IVec2) -> Option<&Self::Tile>;
         fn get_mut(&mut self, position: IVec2) -> Option<&mut Self::Tile>;
@@ -202,25 +1861,452 @@ pub mod traits {
         {
             TileRegion::new(self, start, size)
         }
+
+        /// Cells above, right of, below and left of a cell, None outside of the layer
+        fn neighbors4(&self, position: IVec2) -> [Option<&Self::Tile>; 4] {
+            [0, 2, 4, 6].map(|neighbor| {
+                let (x, y) = super::NEIGHBOR_OFFSETS[neighbor];
+                self.get(<IVec2 as VectorImpl>::add(
+                    position,
+                    <IVec2 as VectorImpl>::new(x as _, y as _),
+                ))
+            })
+        }
+
+        /// Cells around a cell clockwise from above, None outside of the layer
+        fn neighbors8(&self, position: IVec2) -> [Option<&Self::Tile>; 8] {
+            super::NEIGHBOR_OFFSETS.map(|(x, y)| {
+                self.get(<IVec2 as VectorImpl>::add(
+                    position,
+                    <IVec2 as VectorImpl>::new(x as _, y as _),
+                ))
+            })
+        }
+
+        /// Neighbours matching the predicate as bits, clockwise from above: 1 is above,
+        /// 2 above right, 4 right... up to 128 above left.
+        /// BLOB_INDICES turns it into the tile of a 47 tiles blob set. Empty layers give 0
+        fn neighbor_mask(
+            &self,
+            position: IVec2,
+            predicate: impl Fn(&Self::Tile) -> bool,
+            out_of_bounds: OutOfBounds,
+        ) -> u8 {
+            let size = self.size();
+            let (width, height) = (
+                <UVec2 as VectorImpl>::x(&size) as i32,
+                <UVec2 as VectorImpl>::y(&size) as i32,
+            );
+            let (x, y) = (
+                <IVec2 as VectorImpl>::x(&position) as i32,
+                <IVec2 as VectorImpl>::y(&position) as i32,
+            );
+            if width <= 0 || height <= 0 {
+                return 0;
+            }
+            let matching = |x: i32, y: i32| {
+                self.get(<IVec2 as VectorImpl>::new(x as _, y as _))
+                    .is_some_and(&predicate)
+            };
+            let mut mask = 0;
+            for (bit, (dx, dy)) in super::NEIGHBOR_OFFSETS.into_iter().enumerate() {
+                let (x, y) = (x + dx, y + dy);
+                let inside = x >= 0 && y >= 0 && x < width && y < height;
+                let matches = match out_of_bounds {
+                    _ if inside => matching(x, y),
+                    OutOfBounds::Empty => false,
+                    OutOfBounds::Matching => true,
+                    OutOfBounds::Clamp => matching(x.clamp(0, width - 1), y.clamp(0, height - 1)),
+                };
+                if matches {
+                    mask |= 1 << bit;
+                }
+            }
+            mask
+        }
+
+        /// First cell matching the predicate along a ray, closer than max_distance.
+        /// Origin and distances are in pixels, cells are placed at total_offset()
+        fn raycast(
+            &self,
+            origin: FVec2,
+            direction: FVec2,
+            max_distance: f32,
+            predicate: impl Fn(&Self::Tile) -> bool,
+        ) -> Option<RayHit> {
+            let size = self.size();
+            let offset = self.total_offset();
+            super::cast_ray(
+                (
+                    <UVec2 as VectorImpl>::x(&size) as i32,
+                    <UVec2 as VectorImpl>::y(&size) as i32,
+                ),
+                Self::GRID_SIZE,
+                (
+                    <IVec2 as VectorImpl>::x(&offset) as f32,
+                    <IVec2 as VectorImpl>::y(&offset) as f32,
+                ),
+                (
+                    <FVec2 as VectorImpl>::x(&origin) as f32,
+                    <FVec2 as VectorImpl>::y(&origin) as f32,
+                ),
+                (
+                    <FVec2 as VectorImpl>::x(&direction) as f32,
+                    <FVec2 as VectorImpl>::y(&direction) as f32,
+                ),
+                max_distance,
+                |x, y| {
+                    self.get(<IVec2 as VectorImpl>::new(x as _, y as _))
+                        .is_some_and(&predicate)
+                },
+            )
+        }
+
+        /// Whether no cell matching the predicate is between two points (in pixels)
+        fn line_of_sight(&self, a: FVec2, b: FVec2, blocking: impl Fn(&Self::Tile) -> bool) -> bool {
+            let direction = <FVec2 as VectorImpl>::sub(b, a);
+            let distance = (<FVec2 as VectorImpl>::x(&direction) as f32)
+                .hypot(<FVec2 as VectorImpl>::y(&direction) as f32);
+            match self.raycast(a, direction, distance, blocking) {
+                Some(hit) => hit.distance >= distance,
+                None => true,
+            }
+        }
     }
 
     // * --- Actual layers--- * //
-    use super::EntityObject;
-    use super::{Tile, TilesetID};
+    use super::{EntitiesOf, EntitiesOfMut, Entity, EntityGrid, EntityObject};
+    use super::{TypedEntity, TypedEntityMut};
+    use super::{AutoRuleGroup, AutoTile, CellRect, Tile, TilesetID};
+    use super::{CellBlock, OutsideLayer};
+    use super::{Neighbourhood, OutOfBounds, PathBuffer, RayHit, Region, RegionMap};
+
+    /// A layer whose cells can be edited
+    pub trait EditableLayer: IndexableLayer {
+        /// How a cell is stored
+        type Cell: Clone + Default;
+
+        /// Cells row by row
+        fn cells(&self) -> &[Self::Cell];
+        fn cells_mut(&mut self) -> &mut [Self::Cell];
+        /// Replaces the size and all cells of the layer
+        fn replace_cells(&mut self, size: UVec2, cells: Vec<Self::Cell>);
+        /// Updates what is computed from cells (like auto tiles) after a rectangle was edited
+        fn cells_changed(&mut self, start: IVec2, size: UVec2) {}
+
+        /// Replaces a cell, returning the previous one
+        fn set(&mut self, position: IVec2, cell: Self::Cell) -> Result<Self::Cell, OutsideLayer> {
+            let index = super::cell_index(self.size(), position).ok_or(OutsideLayer { position })?;
+            let previous = std::mem::replace(&mut self.cells_mut()[index], cell);
+            self.cells_changed(position, <UVec2 as VectorImpl>::new(1 as _, 1 as _));
+            Ok(previous)
+        }
+
+        /// Sets the cells of a rectangle, ignoring the ones outside of the layer
+        fn fill_rect(&mut self, start: IVec2, size: UVec2, cell: Self::Cell) {
+            let layer_size = self.size();
+            let ((left, top), (right, bottom)) = super::clip_rect(layer_size, start, size);
+            let width = <UVec2 as VectorImpl>::x(&layer_size) as i32;
+            let cells = self.cells_mut();
+            for y in top..bottom {
+                for x in left..right {
+                    cells[(y * width + x) as usize] = cell.clone();
+                }
+            }
+            if let Some((start, size)) = super::clipped_rect((left, top), (right, bottom)) {
+                self.cells_changed(start, size);
+            }
+        }
+
+        /// Resets every cell
+        fn clear(&mut self) {
+            self.cells_mut().fill(Self::Cell::default());
+            self.cells_changed(<IVec2 as VectorImpl>::new(0 as _, 0 as _), self.size());
+        }
+
+        /// Changes the size of this layer only. The anchor (from 0 to 1, like pivots) is
+        /// the point of the cells that stays in place, new cells are empty
+        fn resize(&mut self, size: UVec2, anchor: FVec2) {
+            let old_size = self.size();
+            let offset = |old: u32, new: u32, anchor: f32| {
+                ((new as f32 - old as f32) * anchor).round() as i32
+            };
+            let offset = (
+                offset(
+                    <UVec2 as VectorImpl>::x(&old_size) as u32,
+                    <UVec2 as VectorImpl>::x(&size) as u32,
+                    <FVec2 as VectorImpl>::x(&anchor) as f32,
+                ),
+                offset(
+                    <UVec2 as VectorImpl>::y(&old_size) as u32,
+                    <UVec2 as VectorImpl>::y(&size) as u32,
+                    <FVec2 as VectorImpl>::y(&anchor) as f32,
+                ),
+            );
+            let moved = self.copy_region(
+                <IVec2 as VectorImpl>::new(-offset.0 as _, -offset.1 as _),
+                size,
+            );
+            self.replace_cells(size, moved.cells);
+        }
+
+        /// Copies the cells of a rectangle, the ones outside of the layer are empty
+        fn copy_region(&self, start: IVec2, size: UVec2) -> CellBlock<Self::Cell> {
+            let cells = self.cells();
+            let layer_size = self.size();
+            let (x, y) = (
+                <IVec2 as VectorImpl>::x(&start) as i32,
+                <IVec2 as VectorImpl>::y(&start) as i32,
+            );
+            let mut copied = Vec::new();
+            for dy in 0..<UVec2 as VectorImpl>::y(&size) as i32 {
+                for dx in 0..<UVec2 as VectorImpl>::x(&size) as i32 {
+                    copied.push(
+                        super::cell_index(
+                            layer_size,
+                            <IVec2 as VectorImpl>::new((x + dx) as _, (y + dy) as _),
+                        )
+                        .map(|index| cells[index].clone())
+                        .unwrap_or_default(),
+                    );
+                }
+            }
+            CellBlock {
+                size,
+                cells: copied,
+            }
+        }
+
+        /// Pastes copied cells with their top-left at start, ignoring the ones that end up
+        /// outside of the layer
+        fn paste_region(&mut self, start: IVec2, block: &CellBlock<Self::Cell>) {
+            let layer_size = self.size();
+            let ((left, top), (right, bottom)) = super::clip_rect(layer_size, start, block.size);
+            let (x, y) = (
+                <IVec2 as VectorImpl>::x(&start) as i32,
+                <IVec2 as VectorImpl>::y(&start) as i32,
+            );
+            let width = <UVec2 as VectorImpl>::x(&layer_size) as i32;
+            let cells = self.cells_mut();
+            for cell_y in top..bottom {
+                for cell_x in left..right {
+                    if let Some(cell) =
+                        block.get(<IVec2 as VectorImpl>::new((cell_x - x) as _, (cell_y - y) as _))
+                    {
+                        cells[(cell_y * width + cell_x) as usize] = cell.clone();
+                    }
+                }
+            }
+            if let Some((start, size)) = super::clipped_rect((left, top), (right, bottom)) {
+                self.cells_changed(start, size);
+            }
+        }
+    }
 
     /// An integer grid layer trait
-    pub trait IntGrid: IndexableLayer {}
+    pub trait IntGrid: IndexableLayer {
+        /// Cells matching the predicate, merged into as few rectangles as the greedy
+        /// search finds. Pixel coordinates include total_offset()
+        fn collision_rects(&self, predicate: impl Fn(&Self::Tile) -> bool) -> Vec<CellRect> {
+            let size = self.size();
+            let offset = self.total_offset();
+            super::merge_cells(
+                (
+                    <UVec2 as VectorImpl>::x(&size) as i32,
+                    <UVec2 as VectorImpl>::y(&size) as i32,
+                ),
+                |x, y| {
+                    self.get(<IVec2 as VectorImpl>::new(x as _, y as _))
+                        .is_some_and(&predicate)
+                },
+            )
+            .into_iter()
+            .map(|(position, size)| {
+                CellRect::new(
+                    position,
+                    size,
+                    Self::GRID_SIZE,
+                    (
+                        <IVec2 as VectorImpl>::x(&offset) as i32,
+                        <IVec2 as VectorImpl>::y(&offset) as i32,
+                    ),
+                )
+            })
+            .collect()
+        }
+
+        /// Borders of the cells matching the predicate, as closed loops of corners in pixels,
+        /// placed at total_offset().
+        /// Outer borders go clockwise (with y pointing down) and holes counter-clockwise.
+        /// Repeat the first point to get chains
+        fn collision_outlines(&self, predicate: impl Fn(&Self::Tile) -> bool) -> Vec<Vec<IVec2>> {
+            let size = self.size();
+            let grid_size = Self::GRID_SIZE as i32;
+            let (offset_x, offset_y) = (
+                <IVec2 as VectorImpl>::x(&self.total_offset()) as i32,
+                <IVec2 as VectorImpl>::y(&self.total_offset()) as i32,
+            );
+            super::trace_outlines(
+                (
+                    <UVec2 as VectorImpl>::x(&size) as i32,
+                    <UVec2 as VectorImpl>::y(&size) as i32,
+                ),
+                |x, y| {
+                    self.get(<IVec2 as VectorImpl>::new(x as _, y as _))
+                        .is_some_and(&predicate)
+                },
+            )
+            .into_iter()
+            .map(|outline| {
+                outline
+                    .into_iter()
+                    .map(|(x, y)| {
+                        <IVec2 as VectorImpl>::new(
+                            (offset_x + x * grid_size) as _,
+                            (offset_y + y * grid_size) as _,
+                        )
+                    })
+                    .collect()
+            })
+            .collect()
+        }
+
+        /// Cells matching the predicate connected to start by their sides, including start.
+        /// Empty when start doesn't match
+        fn flood_fill(&self, start: IVec2, predicate: impl Fn(&Self::Tile) -> bool) -> Vec<IVec2> {
+            let size = self.size();
+            super::flood_fill(
+                (
+                    <UVec2 as VectorImpl>::x(&size) as i32,
+                    <UVec2 as VectorImpl>::y(&size) as i32,
+                ),
+                (
+                    <IVec2 as VectorImpl>::x(&start) as i32,
+                    <IVec2 as VectorImpl>::y(&start) as i32,
+                ),
+                |x, y| {
+                    self.get(<IVec2 as VectorImpl>::new(x as _, y as _))
+                        .is_some_and(&predicate)
+                },
+            )
+            .into_iter()
+            .map(|(x, y)| <IVec2 as VectorImpl>::new(x as _, y as _))
+            .collect()
+        }
 
-    /// A tile layer trait
-    pub trait Tiles: IndexableLayer<Tile = Tile> {
+        /// Groups of cells matching the predicate, connected by their sides
+        fn connected_regions(&self, predicate: impl Fn(&Self::Tile) -> bool) -> Vec<Region> {
+            self.region_map(predicate).regions
+        }
+
+        /// Groups of cells matching the predicate, and the group of every cell
+        fn region_map(&self, predicate: impl Fn(&Self::Tile) -> bool) -> RegionMap {
+            let size = self.size();
+            let offset = self.total_offset();
+            RegionMap::new(
+                (
+                    <UVec2 as VectorImpl>::x(&size) as i32,
+                    <UVec2 as VectorImpl>::y(&size) as i32,
+                ),
+                Self::GRID_SIZE,
+                (
+                    <IVec2 as VectorImpl>::x(&offset) as i32,
+                    <IVec2 as VectorImpl>::y(&offset) as i32,
+                ),
+                |x, y| {
+                    self.get(<IVec2 as VectorImpl>::new(x as _, y as _))
+                        .is_some_and(&predicate)
+                },
+            )
+        }
+
+        /// Cheapest path between two cells, including both. `cost` is the cost of entering
+        /// a cell, None when it can't be walked on. The path is the cheapest one as long as
+        /// costs are at least 1
+        fn find_path(
+            &self,
+            start: IVec2,
+            goal: IVec2,
+            neighbourhood: Neighbourhood,
+            cost: impl Fn(&Self::Tile) -> Option<f32>,
+        ) -> Option<Vec<IVec2>> {
+            self.find_path_with(&mut PathBuffer::new(), start, goal, neighbourhood, cost)
+                .map(<[IVec2]>::to_vec)
+        }
+
+        /// find_path, reusing the buffer of previous searches
+        fn find_path_with<'a>(
+            &self,
+            buffer: &'a mut PathBuffer,
+            start: IVec2,
+            goal: IVec2,
+            neighbourhood: Neighbourhood,
+            cost: impl Fn(&Self::Tile) -> Option<f32>,
+        ) -> Option<&'a [IVec2]> {
+            let size = self.size();
+            buffer.find_path(
+                (
+                    <UVec2 as VectorImpl>::x(&size) as i32,
+                    <UVec2 as VectorImpl>::y(&size) as i32,
+                ),
+                (
+                    <IVec2 as VectorImpl>::x(&start) as i32,
+                    <IVec2 as VectorImpl>::y(&start) as i32,
+                ),
+                (
+                    <IVec2 as VectorImpl>::x(&goal) as i32,
+                    <IVec2 as VectorImpl>::y(&goal) as i32,
+                ),
+                neighbourhood,
+                |x, y| {
+                    self.get(<IVec2 as VectorImpl>::new(x as _, y as _))
+                        .and_then(&cost)
+                },
+            )
+        }
+    }
+
+    /// A tile layer trait, its cells are the tiles stacked on them from bottom to top
+    pub trait Tiles: IndexableLayer<Tile = [Tile]> {
         const TILESET_ID: TilesetID;
+
+        /// Tileset used instead of TILESET_ID by this instance
+        fn override_tileset(&self) -> Option<TilesetID>;
+
+        /// Tileset of this instance
+        fn tileset_id(&self) -> TilesetID {
+            self.override_tileset().unwrap_or(Self::TILESET_ID)
+        }
+
+        /// The visible tile of a cell
+        fn top_tile(&self, position: IVec2) -> Option<&Tile> {
+            self.get(position)?.last()
+        }
     }
 
     /// An auto layer trait
     pub trait AutoLayer: Layer {
         const TILESET_ID: TilesetID;
 
-        fn get_autotile(&self, position: IVec2) -> Vec<Tile>;
+        /// Tileset used instead of TILESET_ID by this instance
+        fn override_tileset(&self) -> Option<TilesetID>;
+        /// Random seed of the rules
+        fn seed(&self) -> u32;
+        /// UIDs of the enabled optional rule groups
+        fn optional_rules(&self) -> &[u32];
+
+        /// Tileset of this instance
+        fn tileset_id(&self) -> TilesetID {
+            self.override_tileset().unwrap_or(Self::TILESET_ID)
+        }
+
+        /// Auto tiles of a cell from bottom to top, empty outside of the layer
+        fn autotiles_at(&self, position: IVec2) -> &[AutoTile];
+
+        fn get_autotile(&self, position: IVec2) -> Vec<AutoTile> {
+            self.autotiles_at(position).to_vec()
+        }
+
         fn autotile_rect(&self, start: IVec2, size: UVec2) -> AutoLayerRegion<'_, Self>
         where
             Self: std::marker::Sized,
@@ -229,10 +2315,125 @@ pub mod traits {
         }
     }
 
+    /// A pure auto layer trait, for layers whose rules read the cells of an IntGrid layer
+    pub trait SourcedAutoLayer: AutoLayer {
+        /// IntGrid layer the rules read
+        type Source: IntGrid;
+        const AUTO_RULES: &'static [AutoRuleGroup];
+
+        /// IntGrid value of a cell of the source layer, None outside of it
+        fn int_grid_value(source: &Self::Source, position: IVec2) -> Option<i32>;
+        /// Group UID of an IntGrid value of the source layer
+        fn int_grid_group(value: i32) -> Option<u32>;
+        fn set_autotiles(&mut self, position: IVec2, tiles: Vec<AutoTile>);
+
+        /// Updates the auto tiles after cells of a rectangle of the source layer have changed
+        fn recompute_autotiles(&mut self, source: &Self::Source, start: IVec2, size: UVec2)
+        where
+            Self: std::marker::Sized,
+        {
+            let tiles = super::recompute_autotiles(
+                self,
+                Self::AUTO_RULES,
+                start,
+                size,
+                |x, y| Self::int_grid_value(source, <IVec2 as VectorImpl>::new(x as _, y as _)),
+                Self::int_grid_group,
+            );
+            for (position, tiles) in tiles {
+                self.set_autotiles(position, tiles);
+            }
+        }
+    }
+
+    /// An IntGrid layer trait, for layers whose auto tiles are generated from its values
+    pub trait AutoRules: IntGrid + AutoLayer {
+        const AUTO_RULES: &'static [AutoRuleGroup];
+
+        /// IntGrid value of a cell, None outside of the layer
+        fn int_grid_value(&self, position: IVec2) -> Option<i32>;
+        /// Group UID of an IntGrid value
+        fn int_grid_group(value: i32) -> Option<u32>;
+        fn set_autotiles(&mut self, position: IVec2, tiles: Vec<AutoTile>);
+
+        /// Updates the auto tiles after IntGrid cells of a rectangle have changed
+        fn recompute_autotiles(&mut self, start: IVec2, size: UVec2)
+        where
+            Self: std::marker::Sized,
+        {
+            let tiles = super::recompute_autotiles(
+                self,
+                Self::AUTO_RULES,
+                start,
+                size,
+                |x, y| self.int_grid_value(<IVec2 as VectorImpl>::new(x as _, y as _)),
+                Self::int_grid_group,
+            );
+            for (position, tiles) in tiles {
+                self.set_autotiles(position, tiles);
+            }
+        }
+    }
+
     /// An entities layer trait
     pub trait Entities: Layer {
         fn entities(&self) -> &Vec<EntityObject>;
         fn entities_mut(&mut self) -> &mut Vec<EntityObject>;
+
+        /// Uniform grid over the entities, for layers generated with `--spatial-index`
+        fn spatial_index(&self) -> Option<&EntityGrid> {
+            None
+        }
+
+        /// Entities overlapping the rectangle (in pixels)
+        fn entities_in_rect(&self, start: FVec2, size: FVec2) -> Vec<&EntityObject> {
+            super::query_entities(self.entities(), self.spatial_index(), start, size, |entity| {
+                entity.overlaps(start, size)
+            })
+        }
+
+        /// Entities containing the point (in pixels)
+        fn entities_at_point(&self, point: FVec2) -> Vec<&EntityObject> {
+            let size = <FVec2 as VectorImpl>::new(0 as _, 0 as _);
+            super::query_entities(self.entities(), self.spatial_index(), point, size, |entity| {
+                entity.contains(point)
+            })
+        }
+
+        /// Entities touching the circle (in pixels)
+        fn entities_in_circle(&self, center: FVec2, radius: f32) -> Vec<&EntityObject> {
+            let start = <FVec2 as VectorImpl>::new(
+                (<FVec2 as VectorImpl>::x(&center) as f32 - radius) as _,
+                (<FVec2 as VectorImpl>::y(&center) as f32 - radius) as _,
+            );
+            let size = <FVec2 as VectorImpl>::new((radius * 2.0) as _, (radius * 2.0) as _);
+            super::query_entities(self.entities(), self.spatial_index(), start, size, |entity| {
+                entity.distance_to(center) <= radius
+            })
+        }
+
+        /// Entity closest to the point (in pixels), by distance to its rectangle
+        fn nearest_entity(&self, point: FVec2) -> Option<&EntityObject> {
+            super::nearest_entity(self.entities(), self.spatial_index(), point)
+        }
+
+        /// Entities of one type
+        fn iter_of<T: EntityType>(&self) -> EntitiesOf<'_, T> {
+            self.entities().iter().filter_map(TypedEntity::from_object)
+        }
+
+        /// Mutable entities of one type
+        fn iter_of_mut<T: EntityType>(&mut self) -> EntitiesOfMut<'_, T> {
+            self.entities_mut()
+                .iter_mut()
+                .filter_map(TypedEntityMut::from_object)
+        }
+    }
+
+    /// A trait for the structs of entity types
+    pub trait EntityType: Sized {
+        fn from_entity(entity: &Entity) -> Option<&Self>;
+        fn from_entity_mut(entity: &mut Entity) -> Option<&mut Self>;
     }
 
     macro_rules! rectangular_region {
@@ -274,5 +2475,5 @@ pub mod traits {
     }
 
     rectangular_region!(TileRegion(IndexableLayer) -> Option<&'a S::Tile>: self -> self.source.get(self.position));
-    rectangular_region!(AutoLayerRegion(AutoLayer) -> Vec<Tile>: self -> self.source.get_autotile(self.position));
+    rectangular_region!(AutoLayerRegion(AutoLayer) -> Vec<AutoTile>: self -> self.source.get_autotile(self.position));
 }
@@ -1,14 +1,27 @@
 use crate::definitions::*;
 
 fn preprocess_header(header: &str, preferences: &Preferences) -> String {
-    header.replace(
+    let header = header.replace(
         "[SERDE]",
         if preferences.serde {
             "serde::Serialize, serde::Deserialize, "
         } else {
             ""
         },
-    )
+    );
+    // #[SERDE(...)] attributes become #[serde(...)], or are removed without serde
+    let mut processed = String::with_capacity(header.len());
+    let mut rest = header.as_str();
+    while let Some(start) = rest.find("#[SERDE(") {
+        processed.push_str(&rest[..start]);
+        let end = start + rest[start..].find(")]\n").expect("Unclosed #[SERDE(") + 3;
+        if preferences.serde {
+            processed.push_str(&rest[start..end].replacen("#[SERDE(", "#[serde(", 1));
+        }
+        rest = &rest[end..];
+    }
+    processed.push_str(rest);
+    processed
 }
 
 pub fn generate_defs(
@@ -22,15 +35,15 @@ pub fn generate_defs(
         "define_vectors!();",
         &if let Some(vector_type) = &preferences.vector {
             format!(
-                            r"
+                r"
 type UVec2 = {};
 type IVec2 = {};
 type FVec2 = {};
 ",
-            vector_type.replace("<T>", "<u32>"),
-            vector_type.replace("<T>", "<i32>"),
-            vector_type.replace("<T>", "<f32>"),
-                        )
+                vector_type.replace("<T>", "<u32>"),
+                vector_type.replace("<T>", "<i32>"),
+                vector_type.replace("<T>", "<f32>"),
+            )
         } else {
             preprocess_header(include_str!("templates/math.rs"), preferences)
         },
@@ -64,8 +77,7 @@ type FVec2 = {};
                                 $mutability,
                                 "(self.entity),\n"
                             ),
-                            index,
-                            preferences.to_case(&layer.identifier, Case::Snake)
+                            index, definitions.names.layers[&layer.identifier].member
                         )
                     })
                     .collect::<String>(),
@@ -75,12 +87,20 @@ type FVec2 = {};
     let header = replace_get_layer!(header, "");
     let header = replace_get_layer!(header, "_mut");
     code.raw(header);
+    generate_blob_tables(code);
 
     for tileset in &project.defs.tilesets {
         definitions.tilesets.insert(
             tileset.uid,
             RsTilesetDefinition {
                 tile_size: tileset.tile_grid_size as _,
+                columns: tileset.c_wid as _,
+                opaque_tiles: tileset
+                    .cached_pixel_data
+                    .as_ref()
+                    .and_then(|data| data.get("opaqueTiles")?.as_ref()?.as_str())
+                    .map(|opaque_tiles| opaque_tiles.chars().map(|tile| tile == '1').collect())
+                    .unwrap_or_default(),
             },
         );
     }
@@ -88,10 +108,23 @@ type FVec2 = {};
     code.raw("/* --- Definitions --- */");
     code.raw("/* Enums */");
     for enum_json in &project.defs.enums {
-        let enum_rs = code.new_enum(&enum_json.identifier).vis("pub");
+        let enum_name = definitions.names.types.insert(
+            preferences,
+            &enum_json.identifier,
+            Case::Pascal,
+            "enum",
+        );
+        let mut enum_definition = RsEnumDefinition {
+            identifier: enum_json.identifier.clone(),
+            ..Default::default()
+        };
+        let mut variants = Namespace::default();
+        let enum_rs = code.new_enum(&enum_name).vis("pub");
         derive_rust_object!(enum_rs preferences.serde, Hash !partial Eq, Ord);
         for value in &enum_json.values {
-            enum_rs.new_variant(&value.id);
+            let variant = variants.insert(preferences, &value.id, Case::Pascal, "enum value");
+            enum_rs.new_variant(&variant);
+            enum_definition.variants.insert(value.id.clone(), variant);
         }
 
         macro_rules! generate_get_const {
@@ -104,14 +137,17 @@ type FVec2 = {};
                     .push_block({
                         let mut match_block = Block::new("match self");
                         for $variant in &enum_json.values {
-                            match_block.line(format!("Self::{} => {},", $variant.id, $line));
+                            match_block.line(format!(
+                                "Self::{} => {},",
+                                enum_definition.variants[&$variant.id], $line
+                            ));
                         }
                         match_block
                     });
             };
         }
 
-        let enum_impl = code.new_impl(&enum_json.identifier);
+        let enum_impl = code.new_impl(&enum_name);
         generate_get_const!(enum_impl color -> Option<Color>; variant => if variant.color >= 0 {
             format!("Some(<Color as ColorImpl>::from_hex({}))", variant.color)
         } else {
@@ -120,13 +156,46 @@ type FVec2 = {};
 
         if let Some(tileset) = enum_json.icon_tileset_uid {
             enum_impl.associate_const("TILESET_ID", "TilesetID", tileset.to_string(), "pub");
-            generate_get_const!(enum_impl icon -> Option<UVec2>; variant => if let Some(tile) = &variant.tile_rect {
-                let tileset = definitions.tilesets.get(&tileset).context("Enum icon tileset was not found!")?;
-                format!("Some(<UVec2 as VectorImpl>::new({} as _, {} as _))", tile.x as u32 / tileset.tile_size, tile.y as u32 / tileset.tile_size)
+            generate_get_const!(enum_impl icon -> Option<TileRect>; variant => if let Some(tile) = &variant.tile_rect {
+                format!("Some({})", definitions.format_tile_rect(tile).context("Enum icon tileset was not found!")?)
             } else {
                 "None".to_owned()
             });
         }
+        definitions.enums.insert(enum_name, enum_definition);
     }
     Ok(())
 }
+
+/// Lookup tables between neighbor masks and the 47 tiles of blob autotiling
+fn generate_blob_tables(code: &mut Scope) {
+    // A corner only matters when both of its sides match
+    let canonical = |mask: u8| {
+        let mut canonical = mask & 0b0101_0101;
+        for corner in [1, 3, 5, 7] {
+            let sides = 1 << (corner - 1) | 1 << ((corner + 1) % 8);
+            if mask & sides == sides {
+                canonical |= mask & 1 << corner;
+            }
+        }
+        canonical
+    };
+    let masks = (0..=u8::MAX).map(canonical).sorted().dedup().collect_vec();
+    let indices = (0..=u8::MAX)
+        .map(|mask| masks.binary_search(&canonical(mask)).unwrap())
+        .collect_vec();
+
+    code.raw("/* --- Blob autotiling --- */");
+    code.raw(format!(
+        "/// Index (0 to 46) of the blob tile for each neighbor mask\npub const BLOB_INDICES: [u8; 256] = [\n{}\n];",
+        indices
+            .chunks(16)
+            .map(|row| format!("    {},", row.iter().join(", ")))
+            .join("\n")
+    ));
+    code.raw(format!(
+        "/// Neighbor mask of each blob tile, without corners next to unmatched sides\npub const BLOB_MASKS: [u8; {}] = [{}];",
+        masks.len(),
+        masks.iter().join(", ")
+    ));
+}
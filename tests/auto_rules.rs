@@ -0,0 +1,33 @@
+use std::path::Path;
+use std::process::Command;
+
+/// Generates code for the demo project, then builds and runs tests/fixtures/recompute.rs with it
+#[test]
+fn recompute_autotiles() {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let output = Path::new(env!("CARGO_TARGET_TMPDIR")).join("recompute");
+    std::fs::create_dir_all(&output).unwrap();
+
+    let generated = Command::new(env!("CARGO_BIN_EXE_ldtk-codegen"))
+        .arg(manifest.join("demo/src/demo.ldtk"))
+        .arg("-o")
+        .arg(output.join("demo.rs"))
+        .status()
+        .unwrap();
+    assert!(generated.success());
+    std::fs::copy(
+        manifest.join("tests/fixtures/recompute.rs"),
+        output.join("main.rs"),
+    )
+    .unwrap();
+
+    let built = Command::new("rustc")
+        .args(["--edition", "2021", "--crate-name", "recompute", "-o"])
+        .arg(output.join("recompute"))
+        .arg(output.join("main.rs"))
+        .status()
+        .unwrap();
+    assert!(built.success());
+    let ran = Command::new(output.join("recompute")).status().unwrap();
+    assert!(ran.success());
+}
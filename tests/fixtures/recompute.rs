@@ -0,0 +1,35 @@
+//! Built against the code generated from demo.ldtk by tests/auto_rules.rs
+mod demo;
+use demo::math::Vec2;
+use demo::traits::*;
+
+fn autotiles(layer: &demo::Collisions) -> Vec<String> {
+    layer
+        .autotile_rect(Vec2::new(0, 0), layer.size())
+        .map(|(position, tiles)| format!("{position:?} {tiles:?}"))
+        .collect()
+}
+
+fn main() {
+    let world = demo::World::load();
+    let mut index = 0;
+    while let Some(level) = world.get(index) {
+        // A full recompute reproduces the tiles LDtk baked
+        let mut layer = level.collisions.clone();
+        layer.recompute_autotiles(Vec2::new(0, 0), layer.size());
+        assert_eq!(autotiles(&level.collisions), autotiles(&layer));
+
+        // Recomputing around edited cells is the same as recomputing everything
+        let mut partial = level.collisions.clone();
+        for x in 2..5 {
+            for y in 2..4 {
+                partial[Vec2::new(x, y)] = demo::CollisionsTile::Empty;
+            }
+        }
+        let mut full = partial.clone();
+        partial.recompute_autotiles(Vec2::new(2, 2), Vec2::new(3, 2));
+        full.recompute_autotiles(Vec2::new(0, 0), full.size());
+        assert_eq!(autotiles(&partial), autotiles(&full));
+        index += 1;
+    }
+}